@@ -0,0 +1,31 @@
+use std::fs;
+
+/// Loads a Foundry artifact JSON and returns its creation bytecode
+/// (`bytecode.object`) as raw bytes.
+pub fn load_bytecode(artifact_path: &str) -> Vec<u8> {
+    let content = fs::read_to_string(artifact_path)
+        .unwrap_or_else(|e| panic!("failed to read artifact {artifact_path}: {e}"));
+
+    let artifact: serde_json::Value =
+        serde_json::from_str(&content).expect("failed to parse artifact JSON");
+
+    let bytecode_hex = artifact["bytecode"]["object"]
+        .as_str()
+        .expect("artifact has no bytecode.object field")
+        .trim_start_matches("0x");
+
+    hex::decode(bytecode_hex).expect("invalid bytecode hex in artifact")
+}
+
+/// Builds the full init code (bytecode ++ ABI-encoded constructor args) whose
+/// keccak256 hash is what CREATE2 needs.
+pub fn build_init_code(artifact_path: &str, constructor_args_hex: Option<&str>) -> Vec<u8> {
+    let mut init_code = load_bytecode(artifact_path);
+
+    if let Some(args_hex) = constructor_args_hex {
+        let args_hex = args_hex.strip_prefix("0x").unwrap_or(args_hex);
+        init_code.extend(hex::decode(args_hex).expect("invalid constructor_args hex"));
+    }
+
+    init_code
+}
@@ -0,0 +1,38 @@
+//! Periodic checkpointing so a long-running search can be resumed after a
+//! crash, or sharded across machines with `--shard-index`/`--shard-count`.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Checkpoint {
+    /// One past the highest salt-counter index any worker had reached when
+    /// this was saved. Lower indices are not all guaranteed to have been
+    /// tried, so resuming from here can skip some of them.
+    pub next_index: u64,
+    pub attempts: u64,
+}
+
+/// Reads a checkpoint file written by [`save`], if one exists.
+pub fn load(path: &str) -> Option<Checkpoint> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Atomically writes `checkpoint` to `path` via a temp file + rename, so a
+/// crash mid-write never leaves a corrupt checkpoint behind.
+pub fn save(path: &str, checkpoint: Checkpoint) {
+    let tmp_path = format!("{path}.tmp");
+    let json = serde_json::to_string(&checkpoint).expect("serialize checkpoint");
+    if fs::write(&tmp_path, json).is_ok() {
+        let _ = fs::rename(&tmp_path, path);
+    }
+}
+
+/// Removes the checkpoint file once a search completes successfully.
+pub fn clear(path: &str) {
+    if Path::new(path).exists() {
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,114 @@
+use clap::{Parser, Subcommand};
+
+/// Eagle vanity address miner
+///
+/// Modeled on the ethkey subcommand layout: one binary, one subcommand per
+/// address-derivation scheme, so adding e.g. an `eoa` mode later doesn't
+/// require a new crate.
+#[derive(Parser, Debug)]
+#[command(name = "miner", author, version, about = "Vanity address miner", long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Mine a CREATE2 salt whose resulting address matches a pattern
+    Create2(Create2Args),
+
+    /// Mine a secp256k1 EOA keypair whose address matches a pattern
+    Eoa(EoaArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct EoaArgs {
+    /// 40-character hex pattern spec with `?` wildcards, e.g. "47???...???"
+    #[arg(long)]
+    pub pattern: String,
+
+    /// Match the EIP-55 checksummed (mixed-case) rendering
+    #[arg(long)]
+    pub checksum: bool,
+
+    /// Number of worker threads (default: number of CPU cores)
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Write the result to this file as JSON in addition to stdout
+    #[arg(long)]
+    pub output: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct Create2Args {
+    /// This shard's index, for splitting a search across N processes (0-based)
+    #[arg(long, default_value_t = 0)]
+    pub shard_index: u64,
+
+    /// Total number of shards splitting this search
+    #[arg(long, default_value_t = 1)]
+    pub shard_count: u64,
+
+    /// Path to periodically checkpoint search progress to
+    #[arg(long)]
+    pub checkpoint: Option<String>,
+
+    /// Resume from --checkpoint's saved progress instead of starting at salt 0
+    #[arg(long)]
+    pub resume: bool,
+
+    /// CREATE2 factory address (with or without 0x prefix)
+    #[arg(long)]
+    pub factory: String,
+
+    /// Init code hash, with or without 0x prefix (mutually exclusive with --artifact)
+    #[arg(long)]
+    pub init_hash: Option<String>,
+
+    /// Path to a Foundry artifact JSON to compute the init code hash from
+    #[arg(long)]
+    pub artifact: Option<String>,
+
+    /// ABI-encoded constructor arguments as hex (no 0x prefix), appended to the
+    /// artifact bytecode before hashing. Only used with --artifact.
+    #[arg(long)]
+    pub constructor_args: Option<String>,
+
+    /// 40-character hex pattern spec with `?` wildcards, e.g.
+    /// "47????????????????????????????????0ea91e". Mutually exclusive with
+    /// --score.
+    #[arg(long)]
+    pub pattern: Option<String>,
+
+    /// Match the EIP-55 checksummed (mixed-case) rendering: letters in
+    /// --pattern must match case as well as value
+    #[arg(long)]
+    pub checksum: bool,
+
+    /// Gas-golf scoring mode instead of pattern matching: keep mining and
+    /// track the top addresses by this metric. One of "zero-bytes",
+    /// "leading-zero-bytes", "leading-zero-nibbles".
+    #[arg(long)]
+    pub score: Option<String>,
+
+    /// How many top-scoring results to keep (--score only)
+    #[arg(long, default_value_t = 1)]
+    pub top_n: usize,
+
+    /// Stop scoring mode after this many seconds
+    #[arg(long)]
+    pub duration_secs: Option<u64>,
+
+    /// Stop scoring mode after this many attempts
+    #[arg(long)]
+    pub max_attempts: Option<u64>,
+
+    /// Number of worker threads (default: number of CPU cores)
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Write the result to this file as JSON in addition to stdout
+    #[arg(long)]
+    pub output: Option<String>,
+}
@@ -0,0 +1,30 @@
+use tiny_keccak::{Hasher, Keccak};
+
+pub fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    hex::decode(hex).expect("invalid hex string")
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+/// keccak256(0xff ++ factory ++ salt ++ init_code_hash), last 20 bytes.
+pub fn compute_create2_address(factory: &[u8], salt: &[u8; 32], init_code_hash: &[u8]) -> [u8; 20] {
+    let mut hasher = Keccak::v256();
+    hasher.update(&[0xff]);
+    hasher.update(factory);
+    hasher.update(salt);
+    hasher.update(init_code_hash);
+
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&output[12..]);
+    address
+}
@@ -0,0 +1,73 @@
+//! Vanity EOA mining: finds a secp256k1 secret key whose derived Ethereum
+//! address matches a `Pattern`, rather than a CREATE2 salt.
+
+use rayon::prelude::*;
+use secp256k1::{PublicKey, Secp256k1, SecretKey};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use zeroize::Zeroizing;
+
+use crate::create2::keccak256;
+use crate::pattern::Pattern;
+
+#[derive(Serialize, Debug)]
+pub struct EoaResult {
+    /// ⚠️ KEEP SECRET — this is the raw private key for `address`.
+    pub secret_key: String,
+    pub address: String,
+    pub attempts: u64,
+    pub time_seconds: f64,
+}
+
+fn address_from_secret(secp: &Secp256k1<secp256k1::All>, secret: &SecretKey) -> [u8; 20] {
+    let public_key = PublicKey::from_secret_key(secp, secret);
+    let uncompressed = public_key.serialize_uncompressed();
+    let hash = keccak256(&uncompressed[1..]); // drop the 0x04 tag byte
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..32]);
+    address
+}
+
+/// Mines a secp256k1 keypair whose address matches `pattern`.
+pub fn mine(pattern: &Pattern, threads: usize) -> Option<EoaResult> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .ok();
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let start = Instant::now();
+
+    let result = (0u64..).into_par_iter().find_map_any(|_| {
+        if found.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let secp = Secp256k1::new();
+        let secret = Zeroizing::new(SecretKey::new(&mut rand::thread_rng()));
+        let address = address_from_secret(&secp, &secret);
+
+        attempts.fetch_add(1, Ordering::Relaxed);
+
+        if pattern.matches(&address) {
+            found.store(true, Ordering::Relaxed);
+            Some((*secret, address))
+        } else {
+            None
+        }
+    });
+
+    let elapsed = start.elapsed().as_secs_f64();
+    let total_attempts = attempts.load(Ordering::Relaxed);
+
+    result.map(|(secret, address)| EoaResult {
+        secret_key: format!("0x{}", hex::encode(secret.secret_bytes())),
+        address: format!("0x{}", hex::encode(address)),
+        attempts: total_attempts,
+        time_seconds: elapsed,
+    })
+}
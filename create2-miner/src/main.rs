@@ -1,148 +1,152 @@
-use rayon::prelude::*;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
-use tiny_keccak::{Hasher, Keccak};
+mod artifact;
+mod checkpoint;
+mod cli;
+mod create2;
+mod eoa;
+mod fast;
+mod miner;
+mod pattern;
+mod scoring;
 
-/// Standard CREATE2 Factory (immutable-create2-factory)
-/// https://github.com/Arachnid/deterministic-deployment-proxy
-const CREATE2_FACTORY: &str = "0x4e59b44847b379578588920cA78FbF26c0B4956C";
+use clap::Parser;
+use cli::{Cli, Command, Create2Args, EoaArgs};
+use create2::hex_to_bytes;
+use miner::ShardConfig;
+use pattern::Pattern;
+use scoring::ScoreMetric;
+use std::time::Duration;
 
-/// CharmStrategyWETH Init Bytecode Hash (FIXED VERSION with constructor args)
-const INIT_CODE_HASH: &str = "0x36b22c74af57426b6ff9d510eec2b7793aee4ebd90a5c763f032f0561e525309";
-
-/// Target prefix (0x47)
-const TARGET_PREFIX: &[u8] = &[0x47];
+fn main() {
+    let cli = Cli::parse();
 
-fn hex_to_bytes(hex: &str) -> Vec<u8> {
-    let hex = hex.strip_prefix("0x").unwrap_or(hex);
-    hex::decode(hex).expect("Invalid hex string")
+    match cli.command {
+        Command::Create2(args) => run_create2(args),
+        Command::Eoa(args) => run_eoa(args),
+    }
 }
 
-fn compute_create2_address(factory: &[u8], salt: &[u8; 32], init_code_hash: &[u8]) -> [u8; 20] {
-    let mut hasher = Keccak::v256();
-    
-    // keccak256(0xff ++ factory ++ salt ++ initCodeHash)
-    hasher.update(&[0xff]);
-    hasher.update(factory);
-    hasher.update(salt);
-    hasher.update(init_code_hash);
-    
-    let mut output = [0u8; 32];
-    hasher.finalize(&mut output);
-    
-    // Take last 20 bytes (address)
-    let mut address = [0u8; 20];
-    address.copy_from_slice(&output[12..]);
-    address
-}
+fn run_eoa(args: EoaArgs) {
+    let pattern = Pattern::parse(&args.pattern, args.checksum);
+    let threads = args
+        .threads
+        .unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4));
 
-fn matches_prefix(address: &[u8; 20], prefix: &[u8]) -> bool {
-    address.starts_with(prefix)
-}
+    println!("Pattern:   {}{}", args.pattern, if args.checksum { " (checksum)" } else { "" });
+    println!("Threads:   {threads}");
 
-fn main() {
-    println!("🦅 Eagle CREATE2 Vanity Address Miner");
-    println!("{}", "=".repeat(60));
-    println!();
-    println!("🎯 Target Prefix: 0x{}", hex::encode(TARGET_PREFIX));
-    println!("🏭 Factory:       {}", CREATE2_FACTORY);
-    println!("📦 Init Hash:     {}", INIT_CODE_HASH);
-    println!();
-    println!("🚀 Mining with {} threads...", rayon::current_num_threads());
-    println!("{}", "=".repeat(60));
-    println!();
-
-    let factory = hex_to_bytes(CREATE2_FACTORY);
-    let init_code_hash = hex_to_bytes(INIT_CODE_HASH);
-    
-    let found = Arc::new(AtomicBool::new(false));
-    let attempts = Arc::new(AtomicU64::new(0));
-    let start_time = Instant::now();
-    
-    // Spawn a thread to print progress
-    let attempts_clone = Arc::clone(&attempts);
-    let found_clone = Arc::clone(&found);
-    std::thread::spawn(move || {
-        let start = Instant::now();
-        loop {
-            std::thread::sleep(std::time::Duration::from_secs(5));
-            if found_clone.load(Ordering::Relaxed) {
-                break;
+    match eoa::mine(&pattern, threads) {
+        Some(result) => {
+            println!("\nFound!");
+            println!("Address:    {}", result.address);
+            println!("Secret key: {} (⚠️ KEEP SECRET)", result.secret_key);
+            println!("Attempts:   {}", result.attempts);
+            println!("Time:       {:.2}s", result.time_seconds);
+
+            if let Some(output_path) = &args.output {
+                let json = serde_json::to_string_pretty(&result).expect("serialize result");
+                std::fs::write(output_path, json).expect("write output file");
+                println!("Saved to: {output_path} (⚠️ contains a private key)");
             }
-            let count = attempts_clone.load(Ordering::Relaxed);
-            let elapsed = start.elapsed().as_secs_f64();
-            let rate = count as f64 / elapsed;
-            println!("⏱️  Attempts: {:>12} | Rate: {:>10.0} H/s | Time: {:.1}s", 
-                     count, rate, elapsed);
         }
-    });
+        None => println!("Mining interrupted without a match"),
+    }
+}
+
+fn run_create2(args: Create2Args) {
+    let factory = hex_to_bytes(&args.factory);
+
+    let init_code_hash = match (&args.init_hash, &args.artifact) {
+        (Some(hash), None) => hex_to_bytes(hash),
+        (None, Some(path)) => {
+            let init_code = artifact::build_init_code(path, args.constructor_args.as_deref());
+            create2::keccak256(&init_code).to_vec()
+        }
+        _ => panic!("specify exactly one of --init-hash or --artifact"),
+    };
+
+    let threads = args
+        .threads
+        .unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4));
+
+    println!("Factory:   0x{}", hex::encode(&factory));
+    println!("Init Hash: 0x{}", hex::encode(&init_code_hash));
+    println!("Threads:   {threads}");
 
-    // Parallel mining using par_bridge for unbounded iterator
-    let result = (0u64..).into_iter().par_bridge().find_map_any(|nonce| {
-        if found.load(Ordering::Relaxed) {
-            return None;
+    match (&args.pattern, &args.score) {
+        (Some(_), Some(_)) => panic!("specify only one of --pattern or --score"),
+        (None, None) => panic!("specify one of --pattern or --score"),
+        (Some(pattern_spec), None) => {
+            run_pattern_search(&args, &factory, &init_code_hash, pattern_spec, threads)
         }
-        
-        // Create salt from nonce
-        let mut salt = [0u8; 32];
-        salt[24..].copy_from_slice(&nonce.to_be_bytes());
-        
-        // Compute address
-        let address = compute_create2_address(&factory, &salt, &init_code_hash);
-        
-        // Update attempts counter
-        attempts.fetch_add(1, Ordering::Relaxed);
-        
-        // Check if it matches
-        if matches_prefix(&address, TARGET_PREFIX) {
-            found.store(true, Ordering::Relaxed);
-            Some((salt, address, nonce))
-        } else {
-            None
+        (None, Some(metric)) => run_score_search(&args, &factory, &init_code_hash, metric, threads),
+    }
+}
+
+fn run_pattern_search(
+    args: &Create2Args,
+    factory: &[u8],
+    init_code_hash: &[u8],
+    pattern_spec: &str,
+    threads: usize,
+) {
+    let pattern = Pattern::parse(pattern_spec, args.checksum);
+    println!("Pattern:   {}{}", pattern_spec, if args.checksum { " (checksum)" } else { "" });
+
+    let shard = ShardConfig {
+        shard_index: args.shard_index,
+        shard_count: args.shard_count,
+        checkpoint_path: args.checkpoint.clone(),
+        resume: args.resume,
+    };
+
+    match miner::mine(factory, init_code_hash, &pattern, threads, &shard) {
+        Some(result) => {
+            println!("\nFound!");
+            println!("Salt:     {}", result.salt);
+            println!("Address:  {}", result.address);
+            println!("Attempts: {}", result.attempts);
+            println!("Time:     {:.2}s", result.time_seconds);
+
+            if let Some(output_path) = &args.output {
+                let json = serde_json::to_string_pretty(&result).expect("serialize result");
+                std::fs::write(output_path, json).expect("write output file");
+                println!("Saved to: {output_path}");
+            }
         }
-    });
-
-    let elapsed = start_time.elapsed();
-    let total_attempts = attempts.load(Ordering::Relaxed);
-    
-    println!();
-    println!("{}", "=".repeat(60));
-    
-    if let Some((salt, address, nonce)) = result {
-        println!("✅ FOUND MATCHING ADDRESS!");
-        println!("{}", "=".repeat(60));
-        println!();
-        println!("🎉 Address:  0x{}", hex::encode(address));
-        println!("🔑 Salt:     0x{}", hex::encode(salt));
-        println!("🔢 Nonce:    {}", nonce);
-        println!();
-        println!("📊 Statistics:");
-        println!("   Attempts: {}", total_attempts);
-        println!("   Time:     {:.2}s", elapsed.as_secs_f64());
-        println!("   Rate:     {:.0} H/s", total_attempts as f64 / elapsed.as_secs_f64());
-        println!();
-        println!("{}", "=".repeat(60));
-        println!("📝 DEPLOYMENT INSTRUCTIONS");
-        println!("{}", "=".repeat(60));
-        println!();
-        println!("1. Use this salt in your CREATE2 deployment:");
-        println!("   Salt: 0x{}", hex::encode(salt));
-        println!();
-        println!("2. Deploy via CREATE2 Factory:");
-        println!("   Factory: {}", CREATE2_FACTORY);
-        println!("   Function: deploy(bytes memory bytecode, bytes32 salt)");
-        println!();
-        println!("3. The deployed address will be:");
-        println!("   0x{}", hex::encode(address));
-        println!();
-        println!("🔗 Verify with Etherscan CREATE2 Calculator:");
-        println!("   https://etherscan.io/address/{}", CREATE2_FACTORY);
-        println!();
-    } else {
-        println!("❌ Mining interrupted");
+        None => println!("Mining interrupted without a match"),
     }
-    
-    println!("{}", "=".repeat(60));
 }
 
+fn run_score_search(
+    args: &Create2Args,
+    factory: &[u8],
+    init_code_hash: &[u8],
+    metric_spec: &str,
+    threads: usize,
+) {
+    let metric: ScoreMetric = metric_spec.parse().expect("invalid --score metric");
+    let duration = args.duration_secs.map(Duration::from_secs);
+
+    println!("Score:     {metric_spec} (top {})", args.top_n);
+
+    let results = scoring::mine_scored(
+        factory,
+        init_code_hash,
+        metric,
+        duration,
+        args.max_attempts,
+        args.top_n,
+        threads,
+    );
+
+    println!("\nTop {} results:", results.len());
+    for result in &results {
+        println!("  score={} salt={} address={}", result.score, result.salt, result.address);
+    }
+
+    if let Some(output_path) = &args.output {
+        let json = serde_json::to_string_pretty(&results).expect("serialize results");
+        std::fs::write(output_path, json).expect("write output file");
+        println!("Saved to: {output_path}");
+    }
+}
@@ -0,0 +1,127 @@
+use rayon::prelude::*;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::{self, Checkpoint};
+use crate::fast::Create2Midstate;
+use crate::pattern::Pattern;
+
+#[derive(Serialize, Debug)]
+pub struct VanityResult {
+    pub salt: String,
+    pub address: String,
+    pub attempts: u64,
+    pub time_seconds: f64,
+}
+
+/// Shards the 64-bit salt-counter space across `shard_count` cooperating
+/// processes, each trying `salt = (base + k)*shard_count + shard_index` for a
+/// non-overlapping, deterministic (and therefore resumable) subset.
+pub struct ShardConfig {
+    pub shard_index: u64,
+    pub shard_count: u64,
+    pub checkpoint_path: Option<String>,
+    pub resume: bool,
+}
+
+impl Default for ShardConfig {
+    fn default() -> Self {
+        ShardConfig { shard_index: 0, shard_count: 1, checkpoint_path: None, resume: false }
+    }
+}
+
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Mines a CREATE2 salt whose resulting address matches `pattern`. Salts are
+/// derived directly from the loop index, packed into the low 8 bytes of the
+/// 32-byte salt, so shards and `--resume` are reproducible.
+pub fn mine(
+    factory: &[u8],
+    init_code_hash: &[u8],
+    pattern: &Pattern,
+    threads: usize,
+    shard: &ShardConfig,
+) -> Option<VanityResult> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .ok();
+
+    let base_index = shard
+        .checkpoint_path
+        .as_deref()
+        .filter(|_| shard.resume)
+        .and_then(checkpoint::load)
+        .map(|c| c.next_index)
+        .unwrap_or(0);
+
+    let midstate = Create2Midstate::new(factory, init_code_hash);
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let max_k_seen = Arc::new(AtomicU64::new(0));
+    let start = Instant::now();
+
+    // Periodically checkpoint the highest counter offset `k` any worker has
+    // reached, so a crash or `--shard`-split run can resume without redoing
+    // work already covered.
+    if let Some(path) = shard.checkpoint_path.clone() {
+        let found = Arc::clone(&found);
+        let attempts = Arc::clone(&attempts);
+        let max_k_seen = Arc::clone(&max_k_seen);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(CHECKPOINT_INTERVAL);
+            if found.load(Ordering::Relaxed) {
+                break;
+            }
+            checkpoint::save(
+                &path,
+                Checkpoint {
+                    next_index: max_k_seen.load(Ordering::Relaxed) + 1,
+                    attempts: attempts.load(Ordering::Relaxed),
+                },
+            );
+        });
+    }
+
+    let result = (0u64..).into_par_iter().find_map_any(|k| {
+        if found.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let k = base_index + k;
+        let nonce = k
+            .checked_mul(shard.shard_count)
+            .and_then(|v| v.checked_add(shard.shard_index))
+            .expect("salt counter overflowed u64");
+
+        let mut salt = [0u8; 32];
+        salt[24..].copy_from_slice(&nonce.to_be_bytes());
+
+        let address = midstate.address(&salt);
+        attempts.fetch_add(1, Ordering::Relaxed);
+        max_k_seen.fetch_max(k, Ordering::Relaxed);
+
+        if pattern.matches(&address) {
+            found.store(true, Ordering::Relaxed);
+            Some((salt, address))
+        } else {
+            None
+        }
+    });
+
+    let elapsed = start.elapsed().as_secs_f64();
+    let total_attempts = attempts.load(Ordering::Relaxed);
+
+    if let Some(path) = &shard.checkpoint_path {
+        checkpoint::clear(path);
+    }
+
+    result.map(|(salt, address)| VanityResult {
+        salt: format!("0x{}", hex::encode(salt)),
+        address: format!("0x{}", hex::encode(address)),
+        attempts: total_attempts,
+        time_seconds: elapsed,
+    })
+}
@@ -0,0 +1,106 @@
+use crate::create2::keccak256;
+
+/// A compiled address-matching pattern.
+///
+/// `spec` is a 40-character hex string where `?` (or `x`/`X`) marks a
+/// wildcard nibble. This replaces the old hand-rolled prefix/suffix byte
+/// comparisons (and the nibble-vs-byte bug that came with them) with one
+/// `(mask, value)` pair checked across all 20 bytes.
+///
+/// When `checksum` is set, any literal `a`-`f` character in the spec is also
+/// required to match the EIP-55 checksummed case of the candidate address,
+/// not just its value.
+pub struct Pattern {
+    mask: [u8; 20],
+    value: [u8; 20],
+    /// Per-nibble checksum requirement: `Some(true)` = must render uppercase,
+    /// `Some(false)` = must render lowercase, `None` = case not constrained.
+    case: [Option<bool>; 40],
+}
+
+impl Pattern {
+    /// Parses a 40-character hex spec with `?`/`x`/`X` wildcards, e.g.
+    /// `"47????????????????????????????????0ea91e"`.
+    pub fn parse(spec: &str, checksum: bool) -> Self {
+        let chars: Vec<char> = spec.chars().collect();
+        assert_eq!(chars.len(), 40, "pattern spec must be exactly 40 hex characters");
+
+        let mut mask = [0u8; 20];
+        let mut value = [0u8; 20];
+        let mut case = [None; 40];
+
+        for (i, &c) in chars.iter().enumerate() {
+            let byte_idx = i / 2;
+            let high_nibble = i % 2 == 0;
+
+            if c == '?' || c == 'x' || c == 'X' {
+                continue;
+            }
+
+            let nibble = c.to_digit(16).unwrap_or_else(|| panic!("invalid hex char '{c}' in pattern"));
+
+            mask[byte_idx] |= if high_nibble { 0xf0 } else { 0x0f };
+            value[byte_idx] |= if high_nibble {
+                (nibble as u8) << 4
+            } else {
+                nibble as u8
+            };
+
+            if checksum && c.is_ascii_alphabetic() {
+                case[i] = Some(c.is_ascii_uppercase());
+            }
+        }
+
+        Pattern { mask, value, case }
+    }
+
+    pub fn matches(&self, address: &[u8; 20]) -> bool {
+        for i in 0..20 {
+            if (address[i] & self.mask[i]) != self.value[i] {
+                return false;
+            }
+        }
+
+        if self.case.iter().any(Option::is_some) {
+            let checksummed = to_checksum_address(address);
+            for (i, want_upper) in self.case.iter().enumerate() {
+                if let Some(want_upper) = want_upper {
+                    let is_upper = checksummed.as_bytes()[i].is_ascii_uppercase();
+                    if is_upper != *want_upper {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Renders `address` as an EIP-55 checksummed (mixed-case) hex string,
+/// without the `0x` prefix.
+pub fn to_checksum_address(address: &[u8; 20]) -> String {
+    let lower = hex::encode(address);
+    let hash = keccak256(lower.as_bytes());
+
+    lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if c.is_ascii_alphabetic() {
+                let hash_nibble = if i % 2 == 0 {
+                    hash[i / 2] >> 4
+                } else {
+                    hash[i / 2] & 0x0f
+                };
+                if hash_nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            } else {
+                c
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,171 @@
+//! Gas-golf scoring mode: instead of stopping at the first pattern match,
+//! keep mining and track the addresses with the most zero bytes, since
+//! those cost less calldata gas wherever the address shows up in a tx.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::fast::Create2Midstate;
+
+#[derive(Clone, Copy, Debug)]
+pub enum ScoreMetric {
+    /// Total number of zero bytes anywhere in the 20-byte address.
+    ZeroBytes,
+    /// Number of zero bytes at the start of the address.
+    LeadingZeroBytes,
+    /// Number of zero hex nibbles at the start of the address.
+    LeadingZeroNibbles,
+}
+
+impl FromStr for ScoreMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zero-bytes" => Ok(ScoreMetric::ZeroBytes),
+            "leading-zero-bytes" => Ok(ScoreMetric::LeadingZeroBytes),
+            "leading-zero-nibbles" => Ok(ScoreMetric::LeadingZeroNibbles),
+            other => Err(format!("unknown score metric '{other}'")),
+        }
+    }
+}
+
+fn score(address: &[u8; 20], metric: ScoreMetric) -> u32 {
+    match metric {
+        ScoreMetric::ZeroBytes => address.iter().filter(|b| **b == 0).count() as u32,
+        ScoreMetric::LeadingZeroBytes => address.iter().take_while(|b| **b == 0).count() as u32,
+        ScoreMetric::LeadingZeroNibbles => {
+            let mut nibbles = 0u32;
+            for byte in address {
+                if *byte == 0 {
+                    nibbles += 2;
+                } else if byte >> 4 == 0 {
+                    nibbles += 1;
+                    break;
+                } else {
+                    break;
+                }
+            }
+            nibbles
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ScoredResult {
+    pub salt: String,
+    pub address: String,
+    pub score: u32,
+    pub attempts: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    score: u32,
+    salt: [u8; 32],
+    address: [u8; 20],
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Mines CREATE2 salts, keeping the `top_n` highest-scoring addresses found
+/// within the given duration/attempt budget (whichever is hit first).
+pub fn mine_scored(
+    factory: &[u8],
+    init_code_hash: &[u8],
+    metric: ScoreMetric,
+    duration: Option<Duration>,
+    max_attempts: Option<u64>,
+    top_n: usize,
+    threads: usize,
+) -> Vec<ScoredResult> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .ok();
+
+    let midstate = Create2Midstate::new(factory, init_code_hash);
+    let done = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    // Min-heap of the current top `top_n`, so the lowest score is always
+    // the first one evicted once the heap grows past capacity.
+    let leaderboard: Arc<Mutex<BinaryHeap<Reverse<Entry>>>> =
+        Arc::new(Mutex::new(BinaryHeap::with_capacity(top_n + 1)));
+    let start = Instant::now();
+
+    // Runs until the duration/attempt budget is exhausted. Modeled on the
+    // existing `done`-flag + `find_any` idiom used for early termination
+    // across an unbounded parallel range, just never actually "finding"
+    // anything until the budget check trips it.
+    (0u64..).into_par_iter().find_any(|nonce| {
+        if done.load(Ordering::Relaxed) {
+            return true;
+        }
+        if let Some(max) = max_attempts {
+            if attempts.load(Ordering::Relaxed) >= max {
+                done.store(true, Ordering::Relaxed);
+                return true;
+            }
+        }
+        if let Some(budget) = duration {
+            if start.elapsed() >= budget {
+                done.store(true, Ordering::Relaxed);
+                return true;
+            }
+        }
+
+        let mut salt = [0u8; 32];
+        salt[24..].copy_from_slice(&nonce.to_be_bytes());
+
+        let address = midstate.address(&salt);
+        attempts.fetch_add(1, Ordering::Relaxed);
+
+        let entry_score = score(&address, metric);
+        let mut board = leaderboard.lock().unwrap();
+        if board.len() < top_n {
+            board.push(Reverse(Entry { score: entry_score, salt, address }));
+        } else if let Some(Reverse(worst)) = board.peek() {
+            if entry_score > worst.score {
+                board.pop();
+                board.push(Reverse(Entry { score: entry_score, salt, address }));
+            }
+        }
+
+        false
+    });
+
+    let total_attempts = attempts.load(Ordering::Relaxed);
+    let mut results: Vec<Entry> = leaderboard
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|Reverse(entry)| entry.clone())
+        .collect();
+    results.sort_by(|a, b| b.score.cmp(&a.score));
+
+    results
+        .into_iter()
+        .map(|entry| ScoredResult {
+            salt: format!("0x{}", hex::encode(entry.salt)),
+            address: format!("0x{}", hex::encode(entry.address)),
+            score: entry.score,
+            attempts: total_attempts,
+        })
+        .collect()
+}
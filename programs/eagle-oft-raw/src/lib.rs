@@ -1,16 +1,22 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint,
     entrypoint::ProgramResult,
     msg,
     program::invoke_signed,
     program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
     system_instruction,
     sysvar::Sysvar,
 };
+use spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+};
 
 entrypoint!(process_instruction);
 
@@ -29,34 +35,165 @@ pub enum Instruction {
     
     /// Mint EAGLE tokens (relayer only)
     /// Accounts:
-    /// 0. [signer] Authority
+    /// 0. [writable, signer] Authority (pays for the inbound message PDA; not
+    ///    checked as the mint authority once a relayer multisig is set)
     /// 1. [] Config PDA
     /// 2. [writable] Mint account
     /// 3. [writable] Destination token account
     /// 4. [] Token program
-    Mint { amount: u64 },
-    
+    /// 5. [writable] Inbound message PDA, seeds `["inbound", src_chain_id, nonce]`
+    /// 6. [] System program
+    /// 7. [] Rent sysvar
+    /// 8. [] Trusted peer PDA for `src_chain_id`, seeds `["peer", src_chain_id]`
+    /// 9. [] Relayer multisig PDA (required once a relayer multisig is set)
+    /// 10+. [signer] Candidate multisig signers (only read once a relayer
+    ///     multisig is set; need not all be signers of this instruction)
+    Mint {
+        src_chain_id: u32,
+        nonce: u64,
+        guid: [u8; 32],
+        amount: u64,
+    },
+
     /// Burn EAGLE tokens (anyone can burn their own)
     /// Accounts:
     /// 0. [signer] Token owner
-    /// 1. [] Config PDA
+    /// 1. [writable] Config PDA
     /// 2. [writable] Mint account
     /// 3. [writable] Source token account
     /// 4. [] Token program
-    Burn { amount: u64 },
+    /// 5. [] Trusted peer PDA for `dst_chain_id`, seeds `["peer", dst_chain_id]`
+    Burn {
+        dst_chain_id: u32,
+        recipient: [u8; 32],
+        amount: u64,
+    },
+
+    /// Register (or update) the trusted peer address for `chain_id`.
+    /// Accounts:
+    /// 0. [writable, signer] Current authority (pays for the peer PDA)
+    /// 1. [] Config PDA
+    /// 2. [writable] Peer PDA, seeds `["peer", chain_id]`
+    /// 3. [] System program
+    /// 4. [] Rent sysvar
+    SetPeer {
+        chain_id: u32,
+        peer_address: [u8; 32],
+    },
+
+    /// Replace the single relayer authority with an `m`-of-`n` multisig.
+    /// Accounts:
+    /// 0. [writable, signer] Current authority (pays for the multisig PDA)
+    /// 1. [writable] Config PDA
+    /// 2. [writable] Multisig PDA (created on first call, overwritten after)
+    /// 3. [] System program
+    /// 4. [] Rent sysvar
+    SetAuthority { m: u8, signers: Vec<Pubkey> },
+
+    /// Set the hard supply cap and the per-epoch minting rate limit.
+    /// Accounts:
+    /// 0. [signer] Current authority
+    /// 1. [writable] Config PDA
+    SetLimits {
+        max_supply: u64,
+        window_duration: i64,
+        window_limit: u64,
+    },
 }
 
 // Config account structure
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Config {
     pub authority: Pubkey,
     pub mint: Pubkey,
+    pub token_program: Pubkey,
     pub bump: u8,
+    /// When set, relayer mint authorizations are checked against the
+    /// `RelayerMultisig` at this PDA instead of `authority` alone.
+    pub relayer_multisig: Option<Pubkey>,
+    /// Monotonic counter for outbound burns, surfaced in the `Burn` log so
+    /// an off-chain relayer can assign each burn a unique outbound nonce.
+    pub outbound_nonce: u64,
+    /// Hard cap on total mint supply. `0` means uncapped.
+    pub max_supply: u64,
+    /// Length in seconds of the sliding mint-rate window. `0` means no
+    /// rate limit regardless of `window_limit`.
+    pub window_duration: i64,
+    /// Maximum amount that may be minted within `window_duration` seconds
+    /// of `window_start`.
+    pub window_limit: u64,
+    /// Unix timestamp the current window opened at.
+    pub window_start: i64,
+    /// Amount minted so far within the current window.
+    pub window_minted: u64,
 }
 
 // Config PDA seed
 pub const CONFIG_SEED: &[u8] = b"config";
 
+// Maximum number of signers a relayer multisig can hold, matching the SPL
+// Token `Multisig` account's own limit.
+pub const MAX_SIGNERS: usize = 11;
+
+// Relayer multisig PDA seed
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+
+/// An `m`-of-`n` set of relayer signers that can jointly authorize a `Mint`
+/// instruction in place of a single relayer authority.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RelayerMultisig {
+    pub m: u8,
+    pub n: u8,
+    pub signers: [Pubkey; MAX_SIGNERS],
+    pub bump: u8,
+}
+
+// Inbound replay-guard PDA seed prefix
+pub const INBOUND_SEED: &[u8] = b"inbound";
+
+/// Marks a single inbound `(src_chain_id, nonce)` message as processed,
+/// preventing the relayer from minting against it a second time.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct InboundMessage {
+    pub processed: bool,
+    pub amount: u64,
+    pub guid: [u8; 32],
+    pub bump: u8,
+}
+
+// Trusted peer registry PDA seed prefix
+pub const PEER_SEED: &[u8] = b"peer";
+
+/// The trusted OFT deployment on another chain, keyed by `chain_id`. Mints
+/// must name a registered source peer and burns must name a registered
+/// destination peer.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Peer {
+    pub chain_id: u32,
+    pub peer_address: [u8; 32],
+    pub bump: u8,
+}
+
+/// Errors specific to this program, surfaced as `ProgramError::Custom`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OftRawError {
+    /// The `(src_chain_id, nonce)` pair has already been minted.
+    AlreadyProcessed = 0,
+    /// Minting this amount would exceed `Config::max_supply`.
+    SupplyCapExceeded = 1,
+    /// Minting this amount would exceed `Config::window_limit` for the
+    /// current rate-limit window.
+    RateLimitExceeded = 2,
+    /// No trusted peer is registered for the given chain id.
+    PeerNotRegistered = 3,
+}
+
+impl From<OftRawError> for ProgramError {
+    fn from(e: OftRawError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
 pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -66,8 +203,29 @@ pub fn process_instruction(
     
     match instruction {
         Instruction::Initialize => process_initialize(program_id, accounts),
-        Instruction::Mint { amount } => process_mint(program_id, accounts, amount),
-        Instruction::Burn { amount } => process_burn(program_id, accounts, amount),
+        Instruction::Mint {
+            src_chain_id,
+            nonce,
+            guid,
+            amount,
+        } => process_mint(program_id, accounts, src_chain_id, nonce, guid, amount),
+        Instruction::Burn {
+            dst_chain_id,
+            recipient,
+            amount,
+        } => process_burn(program_id, accounts, dst_chain_id, recipient, amount),
+        Instruction::SetPeer {
+            chain_id,
+            peer_address,
+        } => process_set_peer(program_id, accounts, chain_id, peer_address),
+        Instruction::SetAuthority { m, signers } => {
+            process_set_authority(program_id, accounts, m, signers)
+        }
+        Instruction::SetLimits {
+            max_supply,
+            window_duration,
+            window_limit,
+        } => process_set_limits(program_id, accounts, max_supply, window_duration, window_limit),
     }
 }
 
@@ -78,27 +236,54 @@ fn process_initialize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramR
     let config_account = next_account_info(accounts_iter)?;
     let mint_account = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
-    let _token_program = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
     let rent_sysvar = next_account_info(accounts_iter)?;
-    
+
     if !authority.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
-    
+
     // Derive config PDA
     let (config_pda, bump) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
     if config_pda != *config_account.key {
         return Err(ProgramError::InvalidSeeds);
     }
-    
+
+    assert_is_token_program(token_program.key)?;
+    assert_owned_by(mint_account, token_program.key)?;
+
+    // The mint authority must already be the config PDA before we take over
+    // minting for it, whether the mint lives under the classic token program
+    // or Token-2022 (the base `Mint` layout is identical either way, so a
+    // plain `spl_token::state::Mint` unpack of the leading bytes is enough).
+    let mint_state =
+        spl_token::state::Mint::unpack_from_slice(&mint_account.data.borrow()[..spl_token::state::Mint::LEN])?;
+    if mint_state.mint_authority != COption::Some(config_pda) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
     // Create config account
     let config_data = Config {
         authority: *authority.key,
         mint: *mint_account.key,
+        token_program: *token_program.key,
         bump,
+        relayer_multisig: None,
+        outbound_nonce: 0,
+        max_supply: 0,
+        window_duration: 0,
+        window_limit: 0,
+        window_start: 0,
+        window_minted: 0,
     };
     
-    let config_size = std::mem::size_of::<Config>();
+    // Size the account for the largest `Config` can ever serialize to, not
+    // just its initial `relayer_multisig: None` layout: `process_set_authority`
+    // later flips that field to `Some(..)`, which borsh-encodes 32 bytes
+    // longer, and the account can't grow after `create_account` allocates it.
+    let mut sizing_config = config_data.clone();
+    sizing_config.relayer_multisig = Some(Pubkey::default());
+    let config_size = sizing_config.try_to_vec()?.len();
     let rent = Rent::from_account_info(rent_sysvar)?;
     let lamports = rent.minimum_balance(config_size);
     
@@ -123,33 +308,169 @@ fn process_initialize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramR
     Ok(())
 }
 
-fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+fn process_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    src_chain_id: u32,
+    nonce: u64,
+    guid: [u8; 32],
+    amount: u64,
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
-    
+
     let authority = next_account_info(accounts_iter)?;
     let config_account = next_account_info(accounts_iter)?;
     let mint_account = next_account_info(accounts_iter)?;
     let dest_account = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
-    
-    if !authority.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
-    
+    let inbound_message_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let rent_sysvar = next_account_info(accounts_iter)?;
+    let peer_account = next_account_info(accounts_iter)?;
+
     // Verify config PDA
     let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
     if config_pda != *config_account.key {
         return Err(ProgramError::InvalidSeeds);
     }
     
+    // `authority` always pays for the inbound-replay PDA, so it must sign
+    // regardless of whether its identity is also what authorizes the mint.
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
     // Deserialize config
-    let config = Config::try_from_slice(&config_account.data.borrow())?;
-    
-    // Check authority
-    if config.authority != *authority.key {
-        return Err(ProgramError::IllegalOwner);
+    let mut config = Config::try_from_slice(&config_account.data.borrow())?;
+
+    // Check authority: either the lone relayer authority, or a quorum of
+    // signers drawn from the configured relayer multisig.
+    match config.relayer_multisig {
+        None => {
+            if config.authority != *authority.key {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+        }
+        Some(multisig_pda) => {
+            let multisig_account = next_account_info(accounts_iter)?;
+            if multisig_pda != *multisig_account.key {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            let multisig = RelayerMultisig::try_from_slice(&multisig_account.data.borrow())?;
+            require_multisig_quorum(&multisig, accounts_iter.as_slice())?;
+        }
     }
-    
+
+    if config.token_program != *token_program.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if config.mint != *mint_account.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The source chain must have a registered trusted peer before we'll mint
+    // against a message claiming to come from it.
+    let (peer_pda, _) = Pubkey::find_program_address(&[PEER_SEED, &src_chain_id.to_le_bytes()], program_id);
+    if peer_pda != *peer_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if peer_account.data_is_empty() {
+        return Err(OftRawError::PeerNotRegistered.into());
+    }
+
+    assert_owned_by(config_account, program_id)?;
+    assert_is_token_program(token_program.key)?;
+    let dest_token_account = assert_initialized_token_account(dest_account, token_program.key)?;
+    if dest_token_account.mint != config.mint {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Guard against the relayer replaying the same inbound message: derive
+    // the PDA for this `(src_chain_id, nonce)` pair and require it doesn't
+    // exist yet, then create it so a second attempt fails here.
+    let (inbound_pda, inbound_bump) = Pubkey::find_program_address(
+        &[INBOUND_SEED, &src_chain_id.to_le_bytes(), &nonce.to_le_bytes()],
+        program_id,
+    );
+    if inbound_pda != *inbound_message_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !inbound_message_account.data_is_empty() {
+        return Err(OftRawError::AlreadyProcessed.into());
+    }
+
+    let inbound_data = InboundMessage {
+        processed: true,
+        amount,
+        guid,
+        bump: inbound_bump,
+    };
+    let inbound_size = inbound_data.try_to_vec()?.len();
+    let rent = Rent::from_account_info(rent_sysvar)?;
+    let inbound_lamports = rent.minimum_balance(inbound_size);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            authority.key,
+            inbound_message_account.key,
+            inbound_lamports,
+            inbound_size as u64,
+            program_id,
+        ),
+        &[
+            authority.clone(),
+            inbound_message_account.clone(),
+            system_program.clone(),
+        ],
+        &[&[
+            INBOUND_SEED,
+            &src_chain_id.to_le_bytes(),
+            &nonce.to_le_bytes(),
+            &[inbound_bump],
+        ]],
+    )?;
+    inbound_data.serialize(&mut &mut inbound_message_account.data.borrow_mut()[..])?;
+
+    // If the mint carries a Token-2022 `TransferFeeConfig` extension, the
+    // bridged amount needs to be grossed up: the relayer is replicating an
+    // exact deposit made on the source chain, but the destination account
+    // will lose a transfer fee the moment it moves those tokens again, so we
+    // mint enough extra for `amount` to still be what lands after that fee.
+    let amount_to_mint = gross_up_for_transfer_fee(token_program.key, mint_account, amount)?;
+
+    // Enforce the hard supply cap (0 = uncapped).
+    if config.max_supply > 0 {
+        let current_supply = spl_token::state::Mint::unpack_from_slice(
+            &mint_account.data.borrow()[..spl_token::state::Mint::LEN],
+        )?
+        .supply;
+        let new_supply = current_supply
+            .checked_add(amount_to_mint)
+            .ok_or(ProgramError::InvalidArgument)?;
+        if new_supply > config.max_supply {
+            return Err(OftRawError::SupplyCapExceeded.into());
+        }
+    }
+
+    // Enforce the sliding-window mint rate limit (0 = no limit), rolling the
+    // window over whenever it has elapsed.
+    if config.window_limit > 0 && config.window_duration > 0 {
+        let now = Clock::get()?.unix_timestamp;
+        if now - config.window_start >= config.window_duration {
+            config.window_start = now;
+            config.window_minted = 0;
+        }
+        let new_window_minted = config
+            .window_minted
+            .checked_add(amount_to_mint)
+            .ok_or(ProgramError::InvalidArgument)?;
+        if new_window_minted > config.window_limit {
+            return Err(OftRawError::RateLimitExceeded.into());
+        }
+        config.window_minted = new_window_minted;
+        config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+    }
+
     // Mint tokens
     let mint_ix = spl_token::instruction::mint_to(
         token_program.key,
@@ -157,9 +478,9 @@ fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> P
         dest_account.key,
         config_account.key,
         &[],
-        amount,
+        amount_to_mint,
     )?;
-    
+
     invoke_signed(
         &mint_ix,
         &[
@@ -170,30 +491,359 @@ fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> P
         ],
         &[&[CONFIG_SEED, &[config.bump]]],
     )?;
-    
-    msg!("Minted {} EAGLE", amount);
+
+    msg!(
+        "Minted {} EAGLE ({} requested, {} grossed up for transfer fee) for src_chain_id={} nonce={}",
+        amount_to_mint,
+        amount,
+        amount_to_mint - amount,
+        src_chain_id,
+        nonce
+    );
     Ok(())
 }
 
-fn process_burn(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+/// Computes how much to mint so that, after the destination account pays a
+/// Token-2022 transfer fee on its next transfer, the recipient still nets
+/// `net_amount`. Mints under the classic token program (and Token-2022 mints
+/// without the `TransferFeeConfig` extension) are returned unchanged.
+fn gross_up_for_transfer_fee(
+    token_program_id: &Pubkey,
+    mint_account: &AccountInfo,
+    net_amount: u64,
+) -> Result<u64, ProgramError> {
+    if *token_program_id != spl_token_2022::id() {
+        return Ok(net_amount);
+    }
+
+    let mint_data = mint_account.data.borrow();
+    let mint_with_extensions = match StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data) {
+        Ok(state) => state,
+        Err(_) => return Ok(net_amount),
+    };
+
+    let fee_config = match mint_with_extensions.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config,
+        Err(_) => return Ok(net_amount),
+    };
+
+    let epoch = Clock::get()?.epoch;
+    fee_config
+        .calculate_inverse_epoch_fee(epoch, net_amount)
+        .and_then(|fee| net_amount.checked_add(fee))
+        .ok_or(ProgramError::InvalidArgument)
+}
+
+/// Requires at least `multisig.m` distinct signers of `multisig.signers`
+/// among `remaining_accounts`, rejecting repeated votes from the same key.
+fn require_multisig_quorum(
+    multisig: &RelayerMultisig,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let known_signers = &multisig.signers[..multisig.n as usize];
+
+    let mut approved: Vec<Pubkey> = Vec::new();
+    for account in remaining_accounts {
+        if !account.is_signer || !known_signers.contains(account.key) {
+            continue;
+        }
+        if approved.contains(account.key) {
+            continue;
+        }
+        approved.push(*account.key);
+    }
+
+    if (approved.len() as u8) < multisig.m {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------
+// Account validation helpers
+// ----------------------------------------------------------------------
+
+/// Requires `account` to be owned by `owner`.
+fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner != owner {
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
+
+/// Requires `token_program_id` to be either the classic SPL Token program
+/// or Token-2022, rejecting any other program masquerading as one.
+fn assert_is_token_program(token_program_id: &Pubkey) -> ProgramResult {
+    if *token_program_id != spl_token::id() && *token_program_id != spl_token_2022::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+/// Requires `account` to be an initialized SPL token account owned by
+/// `token_program_id`, returning its unpacked state.
+fn assert_initialized_token_account(
+    account: &AccountInfo,
+    token_program_id: &Pubkey,
+) -> Result<spl_token::state::Account, ProgramError> {
+    assert_owned_by(account, token_program_id)?;
+    spl_token::state::Account::unpack(&account.data.borrow()[..spl_token::state::Account::LEN])
+        .map_err(|_| ProgramError::UninitializedAccount)
+}
+
+fn process_set_peer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    chain_id: u32,
+    peer_address: [u8; 32],
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
-    
+
+    let authority = next_account_info(accounts_iter)?;
+    let config_account = next_account_info(accounts_iter)?;
+    let peer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let rent_sysvar = next_account_info(accounts_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if config_pda != *config_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    assert_owned_by(config_account, program_id)?;
+    let config = Config::try_from_slice(&config_account.data.borrow())?;
+    if config.authority != *authority.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let (peer_pda, peer_bump) =
+        Pubkey::find_program_address(&[PEER_SEED, &chain_id.to_le_bytes()], program_id);
+    if peer_pda != *peer_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let peer_data = Peer {
+        chain_id,
+        peer_address,
+        bump: peer_bump,
+    };
+    let peer_size = peer_data.try_to_vec()?.len();
+
+    if peer_account.data_is_empty() {
+        let rent = Rent::from_account_info(rent_sysvar)?;
+        let lamports = rent.minimum_balance(peer_size);
+        invoke_signed(
+            &system_instruction::create_account(
+                authority.key,
+                peer_account.key,
+                lamports,
+                peer_size as u64,
+                program_id,
+            ),
+            &[
+                authority.clone(),
+                peer_account.clone(),
+                system_program.clone(),
+            ],
+            &[&[PEER_SEED, &chain_id.to_le_bytes(), &[peer_bump]]],
+        )?;
+    }
+
+    peer_data.serialize(&mut &mut peer_account.data.borrow_mut()[..])?;
+
+    msg!("Registered peer for chain_id={}", chain_id);
+    Ok(())
+}
+
+fn process_set_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    m: u8,
+    signers: Vec<Pubkey>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let authority = next_account_info(accounts_iter)?;
+    let config_account = next_account_info(accounts_iter)?;
+    let multisig_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let rent_sysvar = next_account_info(accounts_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if config_pda != *config_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    assert_owned_by(config_account, program_id)?;
+    let mut config = Config::try_from_slice(&config_account.data.borrow())?;
+    if config.authority != *authority.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if signers.is_empty() || signers.len() > MAX_SIGNERS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if m == 0 || (m as usize) > signers.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (multisig_pda, multisig_bump) = Pubkey::find_program_address(&[MULTISIG_SEED], program_id);
+    if multisig_pda != *multisig_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut signer_slots = [Pubkey::default(); MAX_SIGNERS];
+    signer_slots[..signers.len()].copy_from_slice(&signers);
+
+    let multisig_data = RelayerMultisig {
+        m,
+        n: signers.len() as u8,
+        signers: signer_slots,
+        bump: multisig_bump,
+    };
+
+    let multisig_size = multisig_data.try_to_vec()?.len();
+
+    if multisig_account.data_is_empty() {
+        let rent = Rent::from_account_info(rent_sysvar)?;
+        let lamports = rent.minimum_balance(multisig_size);
+        invoke_signed(
+            &system_instruction::create_account(
+                authority.key,
+                multisig_account.key,
+                lamports,
+                multisig_size as u64,
+                program_id,
+            ),
+            &[
+                authority.clone(),
+                multisig_account.clone(),
+                system_program.clone(),
+            ],
+            &[&[MULTISIG_SEED, &[multisig_bump]]],
+        )?;
+    }
+
+    multisig_data.serialize(&mut &mut multisig_account.data.borrow_mut()[..])?;
+
+    config.relayer_multisig = Some(multisig_pda);
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!(
+        "Relayer authority replaced with a {}-of-{} multisig",
+        m,
+        signers.len()
+    );
+    Ok(())
+}
+
+fn process_set_limits(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_supply: u64,
+    window_duration: i64,
+    window_limit: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let authority = next_account_info(accounts_iter)?;
+    let config_account = next_account_info(accounts_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
+    if config_pda != *config_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    assert_owned_by(config_account, program_id)?;
+    let mut config = Config::try_from_slice(&config_account.data.borrow())?;
+    if config.authority != *authority.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if window_duration < 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    config.max_supply = max_supply;
+    config.window_duration = window_duration;
+    config.window_limit = window_limit;
+    config.window_start = Clock::get()?.unix_timestamp;
+    config.window_minted = 0;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!(
+        "Limits updated: max_supply={} window_duration={}s window_limit={}",
+        max_supply,
+        window_duration,
+        window_limit
+    );
+    Ok(())
+}
+
+fn process_burn(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    dst_chain_id: u32,
+    recipient: [u8; 32],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
     let owner = next_account_info(accounts_iter)?;
     let config_account = next_account_info(accounts_iter)?;
     let mint_account = next_account_info(accounts_iter)?;
     let source_account = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
-    
+    let peer_account = next_account_info(accounts_iter)?;
+
     if !owner.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
-    
+
     // Verify config PDA
     let (config_pda, _) = Pubkey::find_program_address(&[CONFIG_SEED], program_id);
     if config_pda != *config_account.key {
         return Err(ProgramError::InvalidSeeds);
     }
-    
+
+    assert_owned_by(config_account, program_id)?;
+    let mut config = Config::try_from_slice(&config_account.data.borrow())?;
+    if config.token_program != *token_program.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if config.mint != *mint_account.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    assert_is_token_program(token_program.key)?;
+    let source_token_account = assert_initialized_token_account(source_account, token_program.key)?;
+    if source_token_account.mint != config.mint {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The destination chain must have a registered trusted peer before we'll
+    // burn toward it.
+    let (peer_pda, _) = Pubkey::find_program_address(&[PEER_SEED, &dst_chain_id.to_le_bytes()], program_id);
+    if peer_pda != *peer_account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if peer_account.data_is_empty() {
+        return Err(OftRawError::PeerNotRegistered.into());
+    }
+
     // Burn tokens
     let burn_ix = spl_token::instruction::burn(
         token_program.key,
@@ -203,7 +853,7 @@ fn process_burn(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> P
         &[],
         amount,
     )?;
-    
+
     invoke_signed(
         &burn_ix,
         &[
@@ -214,8 +864,24 @@ fn process_burn(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> P
         ],
         &[],
     )?;
-    
-    msg!("Burned {} EAGLE from {}", amount, owner.key);
+
+    // Assign this burn the next outbound nonce so an off-chain relayer can
+    // attribute the corresponding inbound mint on the destination chain.
+    let outbound_nonce = config.outbound_nonce;
+    config.outbound_nonce = config
+        .outbound_nonce
+        .checked_add(1)
+        .ok_or(ProgramError::InvalidArgument)?;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!(
+        "Burned {} EAGLE from {} (outbound_nonce={}, dst_chain_id={}, recipient={:?})",
+        amount,
+        owner.key,
+        outbound_nonce,
+        dst_chain_id,
+        recipient
+    );
     Ok(())
 }
 
@@ -70,14 +70,41 @@ pub mod eagle_registry_solana {
         peer_config.is_active = true;
         peer_config.bump = ctx.bumps.peer_config;
 
+        let inbound_nonce = &mut ctx.accounts.inbound_nonce;
+        inbound_nonce.src_eid = chain_eid;
+        inbound_nonce.highest_processed_nonce = 0;
+        inbound_nonce.bump = ctx.bumps.inbound_nonce;
+
         msg!("Registered peer chain: {} (EID: {})", chain_name, chain_eid);
-        
+
+        Ok(())
+    }
+
+    /// Admin recovery hatch: rewinds or fast-forwards a peer's recorded
+    /// nonce watermark, e.g. to unblock delivery after an out-of-band fix to
+    /// a stuck relayer.
+    pub fn reset_inbound_nonce(
+        ctx: Context<ResetInboundNonce>,
+        src_eid: u32,
+        new_nonce: u64,
+    ) -> Result<()> {
+        ctx.accounts.inbound_nonce.highest_processed_nonce = new_nonce;
+
+        msg!("Reset inbound nonce for EID {} to {}", src_eid, new_nonce);
+
         Ok(())
     }
 
     /// Handle incoming LayerZero message from EVM chains
     /// This would integrate with LayerZero's OApp receive pattern
     /// NOTE: This is a simplified version - full integration requires LayerZero SDK
+    ///
+    /// Replay protection is two-layered: the `consumed_message` PDA is seeded
+    /// by `(src_eid, guid)` and created with Anchor `init`, so reprocessing
+    /// the exact same message fails with an account-already-exists error;
+    /// `inbound_nonce` additionally rejects any nonce at or below the highest
+    /// one already processed for this peer, mirroring LayerZero's nonce
+    /// ordering.
     pub fn lz_receive(
         ctx: Context<LzReceive>,
         src_eid: u32,
@@ -87,15 +114,21 @@ pub mod eagle_registry_solana {
         message: Vec<u8>,
     ) -> Result<()> {
         let registry = &ctx.accounts.registry_config;
-        
+
         require!(registry.is_active, ErrorCode::RegistryInactive);
-        
+
         // Verify the peer is registered
         let peer = &ctx.accounts.peer_config;
         require!(peer.chain_eid == src_eid, ErrorCode::UnknownPeer);
         require!(peer.peer_address == sender, ErrorCode::InvalidSender);
         require!(peer.is_active, ErrorCode::PeerInactive);
 
+        let inbound_nonce = &mut ctx.accounts.inbound_nonce;
+        require!(nonce > inbound_nonce.highest_processed_nonce, ReplayError::NonceAlreadyProcessed);
+        inbound_nonce.highest_processed_nonce = nonce;
+
+        ctx.accounts.consumed_message.bump = ctx.bumps.consumed_message;
+
         // Decode and process message
         // Message format (example): [action_type(1), data(...)]
         if message.is_empty() {
@@ -209,7 +242,7 @@ pub struct RegisterPeerChain<'info> {
         has_one = authority,
     )]
     pub registry_config: Account<'info, RegistryConfig>,
-    
+
     #[account(
         init,
         payer = authority,
@@ -218,31 +251,81 @@ pub struct RegisterPeerChain<'info> {
         bump
     )]
     pub peer_config: Account<'info, PeerChainConfig>,
-    
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InboundNonce::INIT_SPACE,
+        seeds = [b"nonce", &chain_eid.to_le_bytes()],
+        bump
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(src_eid: u32)]
+#[instruction(src_eid: u32, sender: [u8; 32], nonce: u64, guid: [u8; 32])]
 pub struct LzReceive<'info> {
     #[account(
         seeds = [b"registry"],
         bump = registry_config.bump,
     )]
     pub registry_config: Account<'info, RegistryConfig>,
-    
+
     #[account(
         seeds = [b"peer", &src_eid.to_le_bytes()],
         bump = peer_config.bump,
     )]
     pub peer_config: Account<'info, PeerChainConfig>,
-    
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ConsumedMessage::INIT_SPACE,
+        seeds = [b"consumed", &src_eid.to_le_bytes(), &guid],
+        bump
+    )]
+    pub consumed_message: Account<'info, ConsumedMessage>,
+
+    #[account(
+        mut,
+        seeds = [b"nonce", &src_eid.to_le_bytes()],
+        bump = inbound_nonce.bump,
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
     /// The LayerZero endpoint program would be invoked here
     /// CHECK: This would be validated against registry_config.lz_endpoint
     pub lz_endpoint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(src_eid: u32)]
+pub struct ResetInboundNonce<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"nonce", &src_eid.to_le_bytes()],
+        bump = inbound_nonce.bump,
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -313,6 +396,33 @@ pub struct PeerChainConfig {
     pub bump: u8,
 }
 
+/// Marker account for a single processed LayerZero message, seeded by
+/// `(src_eid, guid)`. Its existence is the replay guard: `init` fails with
+/// an account-already-exists error if the same `guid` is ever submitted
+/// again for that peer.
+#[account]
+#[derive(InitSpace)]
+pub struct ConsumedMessage {
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Tracks the highest-processed nonce for a given peer, mirroring
+/// LayerZero's per-pathway nonce ordering so stale/already-seen nonces are
+/// rejected even if an attacker crafts a fresh `guid` around them.
+#[account]
+#[derive(InitSpace)]
+pub struct InboundNonce {
+    /// The EID of the peer chain this nonce watermark tracks
+    pub src_eid: u32,
+
+    /// Highest nonce processed so far for this peer
+    pub highest_processed_nonce: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
 // ============================================================================
 // Events
 // ============================================================================
@@ -359,3 +469,9 @@ pub enum ErrorCode {
     NameTooLong,
 }
 
+#[error_code]
+pub enum ReplayError {
+    #[msg("Message nonce has already been processed")]
+    NonceAlreadyProcessed,
+}
+
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Burn};
 
 declare_id!("11111111111111111111111111111112"); // Will be updated after deployment
@@ -11,51 +13,98 @@ pub mod eagle_share_oft {
     pub fn initialize(
         ctx: Context<Initialize>,
         decimals: u8,
+        verification_mode: VerificationMode,
     ) -> Result<()> {
         let config = &mut ctx.accounts.config;
         config.authority = ctx.accounts.authority.key();
         config.mint = ctx.accounts.mint.key();
         config.decimals = decimals;
+        config.current_guardian_index = 0;
+        config.verification_mode = verification_mode;
         config.bump = ctx.bumps.config;
-        
+
         msg!("EAGLE Share OFT initialized");
         msg!("Mint: {}", ctx.accounts.mint.key());
         msg!("Decimals: {}", decimals);
-        
+
         Ok(())
     }
 
-    /// Mint EAGLE shares (for bridging IN from other chains)
-    pub fn mint(
-        ctx: Context<MintTokens>,
-        amount: u64,
+    /// Establish the first guardian set (index 0), modeled on Wormhole's
+    /// guardian-set-zero bootstrap: trusted because it's set once by the
+    /// deploying authority, before any funds are at risk.
+    pub fn initialize_guardian_set(
+        ctx: Context<InitializeGuardianSet>,
+        keys: Vec<[u8; 20]>,
+        expiration_time: i64,
     ) -> Result<()> {
-        require!(
-            ctx.accounts.authority.key() == ctx.accounts.config.authority,
-            OftError::Unauthorized
-        );
+        require!(!keys.is_empty(), OftError::NoGuardians);
+        require!(keys.len() <= GuardianSet::MAX_GUARDIANS, OftError::TooManyGuardians);
 
-        let seeds = &[
-            b"config",
-            &[ctx.accounts.config.bump],
-        ];
-        let signer = &[&seeds[..]];
+        let guardian_set = &mut ctx.accounts.guardian_set;
+        guardian_set.index = 0;
+        guardian_set.keys = keys;
+        guardian_set.expiration_time = expiration_time;
+        guardian_set.bump = ctx.bumps.guardian_set;
 
-        token::mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    to: ctx.accounts.to.to_account_info(),
-                    authority: ctx.accounts.config.to_account_info(),
-                },
-                signer,
-            ),
-            amount,
-        )?;
+        ctx.accounts.config.current_guardian_index = 0;
+
+        msg!("Guardian set 0 initialized with {} guardians", guardian_set.keys.len());
+
+        Ok(())
+    }
+
+    /// Rotate to a new guardian set. The rotation itself must be approved by
+    /// quorum of the *current* set, so a stolen authority key alone can't
+    /// hand control of minting to an attacker-chosen guardian set.
+    pub fn update_guardian_set(
+        ctx: Context<UpdateGuardianSet>,
+        new_keys: Vec<[u8; 20]>,
+        new_expiration_time: i64,
+        signatures: Vec<GuardianSignature>,
+    ) -> Result<()> {
+        require!(!new_keys.is_empty(), OftError::NoGuardians);
+        require!(new_keys.len() <= GuardianSet::MAX_GUARDIANS, OftError::TooManyGuardians);
+
+        let new_index = ctx.accounts.old_guardian_set.index + 1;
+        let message = encode_guardian_set_update(new_index, &new_keys, new_expiration_time);
+        let message_hash = keccak::hash(&message).to_bytes();
+        verify_guardian_quorum(&ctx.accounts.old_guardian_set, &message_hash, &signatures)?;
+
+        ctx.accounts.old_guardian_set.expiration_time = Clock::get()?.unix_timestamp;
+
+        let new_guardian_set = &mut ctx.accounts.new_guardian_set;
+        new_guardian_set.index = new_index;
+        new_guardian_set.keys = new_keys;
+        new_guardian_set.expiration_time = new_expiration_time;
+        new_guardian_set.bump = ctx.bumps.new_guardian_set;
+
+        ctx.accounts.config.current_guardian_index = new_index;
+
+        msg!("Rotated to guardian set {}", new_index);
+
+        Ok(())
+    }
+
+    /// Registers the canonical mapping from a remote-chain token to the
+    /// local Solana mint the first time that asset bridges in, so
+    /// front-ends/relayers can answer "what is this wrapped balance's
+    /// origin" instead of only ever seeing an undifferentiated EAGLE mint.
+    pub fn create_wrapped(
+        ctx: Context<CreateWrapped>,
+        origin_eid: u32,
+        origin_token_address: [u8; 32],
+        is_native: bool,
+    ) -> Result<()> {
+        let wrapped_meta = &mut ctx.accounts.wrapped_meta;
+        wrapped_meta.origin_eid = origin_eid;
+        wrapped_meta.origin_token_address = origin_token_address;
+        wrapped_meta.local_mint = ctx.accounts.config.mint;
+        wrapped_meta.is_native = is_native;
+        wrapped_meta.bump = ctx.bumps.wrapped_meta;
+
+        msg!("Registered wrapped asset from EID {} as {}", origin_eid, ctx.accounts.config.mint);
 
-        msg!("Minted {} EAGLE shares to {}", amount, ctx.accounts.to.key());
-        
         Ok(())
     }
 
@@ -81,6 +130,55 @@ pub mod eagle_share_oft {
         Ok(())
     }
 
+    /// Creates the singleton outbound `RateLimit` bucket. `capacity` and
+    /// `refill_per_second` are given in whole EAGLE tokens and scaled
+    /// internally by `10^decimals`, so "1000 EAGLE/day" means the same thing
+    /// regardless of the mint's decimal precision. The bucket starts full.
+    pub fn initialize_rate_limit(
+        ctx: Context<InitializeRateLimit>,
+        capacity: u64,
+        refill_per_second: u64,
+    ) -> Result<()> {
+        let decimals_factor = 10u64
+            .checked_pow(ctx.accounts.config.decimals as u32)
+            .ok_or(OftError::InvalidAmount)?;
+
+        let rate_limit = &mut ctx.accounts.rate_limit;
+        rate_limit.capacity = capacity.checked_mul(decimals_factor).ok_or(OftError::InvalidAmount)?;
+        rate_limit.refill_per_second =
+            refill_per_second.checked_mul(decimals_factor).ok_or(OftError::InvalidAmount)?;
+        rate_limit.available = rate_limit.capacity;
+        rate_limit.last_refill = Clock::get()?.unix_timestamp;
+        rate_limit.bump = ctx.bumps.rate_limit;
+
+        msg!("Rate limit initialized: {} EAGLE capacity, {} EAGLE/s refill", capacity, refill_per_second);
+
+        Ok(())
+    }
+
+    /// Updates the outbound `RateLimit` bucket's capacity/refill rate,
+    /// again given in whole EAGLE tokens. Caps `available` down to the new
+    /// `capacity` if it shrank.
+    pub fn update_rate_limit(
+        ctx: Context<UpdateRateLimit>,
+        capacity: u64,
+        refill_per_second: u64,
+    ) -> Result<()> {
+        let decimals_factor = 10u64
+            .checked_pow(ctx.accounts.config.decimals as u32)
+            .ok_or(OftError::InvalidAmount)?;
+
+        let rate_limit = &mut ctx.accounts.rate_limit;
+        rate_limit.capacity = capacity.checked_mul(decimals_factor).ok_or(OftError::InvalidAmount)?;
+        rate_limit.refill_per_second =
+            refill_per_second.checked_mul(decimals_factor).ok_or(OftError::InvalidAmount)?;
+        rate_limit.available = rate_limit.available.min(rate_limit.capacity);
+
+        msg!("Rate limit updated: {} EAGLE capacity, {} EAGLE/s refill", capacity, refill_per_second);
+
+        Ok(())
+    }
+
     /// Bridge EAGLE shares to another chain via LayerZero
     pub fn bridge_out(
         ctx: Context<BridgeOut>,
@@ -88,6 +186,16 @@ pub mod eagle_share_oft {
         destination_chain_id: u32,
         recipient: [u8; 32],
     ) -> Result<()> {
+        let rate_limit = &mut ctx.accounts.rate_limit;
+        let now = Clock::get()?.unix_timestamp;
+        let elapsed_seconds = now.saturating_sub(rate_limit.last_refill).max(0) as u64;
+        let refilled = elapsed_seconds.saturating_mul(rate_limit.refill_per_second);
+        rate_limit.available = rate_limit.available.saturating_add(refilled).min(rate_limit.capacity);
+        rate_limit.last_refill = now;
+
+        require!(amount <= rate_limit.available, OftError::RateLimitExceeded);
+        rate_limit.available -= amount;
+
         // Burn tokens on Solana
         token::burn(
             CpiContext::new(
@@ -110,17 +218,54 @@ pub mod eagle_share_oft {
         Ok(())
     }
 
-    /// Receive EAGLE shares from another chain via LayerZero
+    /// Receive EAGLE shares from another chain via LayerZero.
+    ///
+    /// Rather than trusting a single `authority` signer, the amount and
+    /// recipient are bound into `payload` (so a leaked signature set can't be
+    /// replayed against a different amount) and authorized by guardian
+    /// quorum: `floor(n*2/3)+1` distinct guardians, each recovering their
+    /// secp256k1 address from a 65-byte `r‖s‖recovery_id` signature over
+    /// `keccak256(payload)`, must sign in strictly ascending guardian-index
+    /// order.
+    ///
+    /// Replay protection mirrors `eagle_registry_solana::lz_receive`:
+    /// `consumed_message` is seeded by `(message.dst_eid, keccak256(payload))`
+    /// and created with `init`, so resubmitting the same payload fails with
+    /// an account-already-exists error; `inbound_nonce` additionally rejects
+    /// any nonce at or below the highest one already processed.
     pub fn bridge_in(
         ctx: Context<BridgeIn>,
-        amount: u64,
-        _source_chain_id: u32,
+        payload: Vec<u8>,
+        signatures: Vec<GuardianSignature>,
+        origin_token_address: [u8; 32],
     ) -> Result<()> {
         require!(
-            ctx.accounts.authority.key() == ctx.accounts.config.authority,
-            OftError::Unauthorized
+            ctx.accounts.config.verification_mode == VerificationMode::GuardianMultisig,
+            OftError::WrongVerificationMode
+        );
+
+        let guardian_set = &ctx.accounts.guardian_set;
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp < guardian_set.expiration_time, OftError::GuardianSetExpired);
+
+        let message = BridgeMessage::decode(&payload)?;
+        let message_hash = keccak::hash(&payload).to_bytes();
+        verify_guardian_quorum(guardian_set, &message_hash, &signatures)?;
+
+        require!(
+            message.recipient == ctx.accounts.to.key().to_bytes(),
+            OftError::RecipientMismatch
         );
 
+        let inbound_nonce = &mut ctx.accounts.inbound_nonce;
+        require!(
+            message.nonce > inbound_nonce.highest_processed_nonce,
+            ReplayError::NonceAlreadyProcessed
+        );
+        inbound_nonce.highest_processed_nonce = message.nonce;
+
+        ctx.accounts.consumed_message.bump = ctx.bumps.consumed_message;
+
         let seeds = &[
             b"config",
             &[ctx.accounts.config.bump],
@@ -138,11 +283,269 @@ pub mod eagle_share_oft {
                 },
                 signer,
             ),
-            amount,
+            message.amount,
         )?;
 
-        msg!("Bridged in {} EAGLE shares", amount);
-        
+        msg!("Bridged in {} EAGLE shares from EID {}", message.amount, message.dst_eid);
+
+        emit!(WrappedAssetBridgedIn {
+            origin_eid: ctx.accounts.wrapped_meta.origin_eid,
+            origin_token_address,
+            local_mint: ctx.accounts.mint.key(),
+            amount: message.amount,
+        });
+
+        Ok(())
+    }
+
+    /// Registers the `InboundNonce` watermark for a remote chain the first
+    /// time it bridges in, analogous to `register_peer_chain` on the
+    /// registry program.
+    pub fn initialize_inbound_nonce(ctx: Context<InitializeInboundNonce>, chain_eid: u32) -> Result<()> {
+        let inbound_nonce = &mut ctx.accounts.inbound_nonce;
+        inbound_nonce.chain_eid = chain_eid;
+        inbound_nonce.highest_processed_nonce = 0;
+        inbound_nonce.bump = ctx.bumps.inbound_nonce;
+
+        msg!("Initialized inbound nonce tracking for EID {}", chain_eid);
+
+        Ok(())
+    }
+
+    /// Admin recovery hatch: rewinds or fast-forwards a chain's recorded
+    /// nonce watermark.
+    pub fn reset_inbound_nonce(ctx: Context<ResetInboundNonce>, chain_eid: u32, new_nonce: u64) -> Result<()> {
+        ctx.accounts.inbound_nonce.highest_processed_nonce = new_nonce;
+
+        msg!("Reset inbound nonce for EID {} to {}", chain_eid, new_nonce);
+
+        Ok(())
+    }
+
+    /// Registers the fixed Schnorr group public key checked by
+    /// `bridge_in_schnorr`, an alternative to guardian multisig for
+    /// operators who'd rather move message attestation off-chain to a
+    /// signing committee and pay O(1) on-chain verification cost regardless
+    /// of committee size. Only meaningful when `initialize` selected
+    /// [`VerificationMode::Schnorr`].
+    pub fn initialize_schnorr_config(
+        ctx: Context<InitializeSchnorrConfig>,
+        group_pubkey_x: [u8; 32],
+        parity: u8,
+    ) -> Result<()> {
+        let schnorr_config = &mut ctx.accounts.schnorr_config;
+        schnorr_config.group_pubkey_x = group_pubkey_x;
+        schnorr_config.parity = parity;
+        schnorr_config.bump = ctx.bumps.schnorr_config;
+
+        msg!("Schnorr group key initialized");
+
+        Ok(())
+    }
+
+    /// Receive EAGLE shares from another chain, authorized by a single
+    /// aggregated Schnorr signature over secp256k1 instead of N-of-M
+    /// guardian multisig. Message layout, replay protection, and
+    /// wrapped-asset handling are identical to `bridge_in`; only the
+    /// signature scheme differs, so this is only callable when `initialize`
+    /// selected [`VerificationMode::Schnorr`].
+    pub fn bridge_in_schnorr(
+        ctx: Context<BridgeInSchnorr>,
+        payload: Vec<u8>,
+        signature: SchnorrSignature,
+        origin_token_address: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.config.verification_mode == VerificationMode::Schnorr,
+            OftError::WrongVerificationMode
+        );
+
+        let message = BridgeMessage::decode(&payload)?;
+        let message_hash = keccak::hash(&payload).to_bytes();
+        verify_schnorr_signature(&ctx.accounts.schnorr_config, &message_hash, &signature)?;
+
+        require!(
+            message.recipient == ctx.accounts.to.key().to_bytes(),
+            OftError::RecipientMismatch
+        );
+
+        let inbound_nonce = &mut ctx.accounts.inbound_nonce;
+        require!(
+            message.nonce > inbound_nonce.highest_processed_nonce,
+            ReplayError::NonceAlreadyProcessed
+        );
+        inbound_nonce.highest_processed_nonce = message.nonce;
+
+        ctx.accounts.consumed_message.bump = ctx.bumps.consumed_message;
+
+        let seeds = &[
+            b"config",
+            &[ctx.accounts.config.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                signer,
+            ),
+            message.amount,
+        )?;
+
+        msg!("Bridged in {} EAGLE shares from EID {} via Schnorr", message.amount, message.dst_eid);
+
+        emit!(WrappedAssetBridgedIn {
+            origin_eid: ctx.accounts.wrapped_meta.origin_eid,
+            origin_token_address,
+            local_mint: ctx.accounts.mint.key(),
+            amount: message.amount,
+        });
+
+        Ok(())
+    }
+
+    /// Registers the canonical `token_id` -> local mint mapping for an EAGLE
+    /// collectible the first time it is mirrored onto Solana, mirroring
+    /// `create_wrapped`'s one-time registration for fungible wrapped assets.
+    /// Must be called once per `token_id` before the first `bridge_in_nft`;
+    /// `origin_chain_id` is fixed here for the life of the token, so a
+    /// collectible bridged Ethereum -> Solana -> Ethereum always round-trips
+    /// through the same mint instead of minting a duplicate.
+    pub fn create_nft_mint(
+        ctx: Context<CreateNftMint>,
+        token_id: u64,
+        origin_chain_id: u32,
+    ) -> Result<()> {
+        let nft_origin = &mut ctx.accounts.nft_origin;
+        nft_origin.token_id = token_id;
+        nft_origin.origin_chain_id = origin_chain_id;
+        nft_origin.local_mint = ctx.accounts.mint.key();
+        nft_origin.bump = ctx.bumps.nft_origin;
+
+        msg!(
+            "Registered NFT token {} from chain {} as mint {}",
+            token_id,
+            origin_chain_id,
+            ctx.accounts.mint.key()
+        );
+
+        Ok(())
+    }
+
+    /// Bridge an EAGLE collectible out to another chain. Burns the token's
+    /// supply-1 mint and emits a message carrying the `token_id` and a
+    /// metadata URI hash, paralleling `bridge_out` but preserving per-token
+    /// identity instead of treating every unit as fungible.
+    pub fn bridge_out_nft(
+        ctx: Context<BridgeOutNft>,
+        token_id: u64,
+        destination_chain_id: u32,
+        recipient: [u8; 32],
+        metadata_uri_hash: [u8; 32],
+    ) -> Result<()> {
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.from.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        msg!("Bridging NFT token {} to chain {}", token_id, destination_chain_id);
+        msg!("Recipient: {:?}", recipient);
+
+        emit!(NftBridgedOut {
+            token_id,
+            origin_chain_id: ctx.accounts.nft_origin.origin_chain_id,
+            destination_chain_id,
+            recipient,
+            metadata_uri_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Receive an EAGLE collectible from another chain. Mints 1 token back to
+    /// the recipient on the token's existing per-`token_id` mint (registered
+    /// via `create_nft_mint`), authorized by the same guardian-quorum scheme
+    /// as `bridge_in` and guarded against replay the same way. `uri` is
+    /// hashed and checked against the `metadata_uri_hash` carried in the
+    /// signed payload, so a relayer can't substitute arbitrary art for a
+    /// given `token_id`.
+    pub fn bridge_in_nft(
+        ctx: Context<BridgeInNft>,
+        payload: Vec<u8>,
+        signatures: Vec<GuardianSignature>,
+        uri: String,
+    ) -> Result<()> {
+        let guardian_set = &ctx.accounts.guardian_set;
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp < guardian_set.expiration_time, OftError::GuardianSetExpired);
+
+        let message = NftBridgeMessage::decode(&payload)?;
+        let message_hash = keccak::hash(&payload).to_bytes();
+        verify_guardian_quorum(guardian_set, &message_hash, &signatures)?;
+
+        require!(
+            message.recipient == ctx.accounts.to.key().to_bytes(),
+            OftError::RecipientMismatch
+        );
+
+        require!(
+            keccak::hash(uri.as_bytes()).to_bytes() == message.metadata_uri_hash,
+            OftError::MetadataHashMismatch
+        );
+
+        let inbound_nonce = &mut ctx.accounts.inbound_nonce;
+        require!(
+            message.nonce > inbound_nonce.highest_processed_nonce,
+            ReplayError::NonceAlreadyProcessed
+        );
+        inbound_nonce.highest_processed_nonce = message.nonce;
+
+        ctx.accounts.consumed_message.bump = ctx.bumps.consumed_message;
+
+        let seeds = &[
+            b"config",
+            &[ctx.accounts.config.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                signer,
+            ),
+            1,
+        )?;
+
+        msg!(
+            "Bridged in NFT token {} from chain {}",
+            message.token_id,
+            ctx.accounts.nft_origin.origin_chain_id
+        );
+
+        emit!(NftBridgedIn {
+            token_id: message.token_id,
+            origin_chain_id: ctx.accounts.nft_origin.origin_chain_id,
+            local_mint: ctx.accounts.mint.key(),
+            uri,
+        });
+
         Ok(())
     }
 }
@@ -152,6 +555,7 @@ pub mod eagle_share_oft {
 // ============================================================================
 
 #[derive(Accounts)]
+#[instruction(decimals: u8, verification_mode: VerificationMode)]
 pub struct Initialize<'info> {
     #[account(
         init,
@@ -161,11 +565,11 @@ pub struct Initialize<'info> {
         bump
     )]
     pub config: Account<'info, OftConfig>,
-    
+
     #[account(
         init,
         payer = authority,
-        mint::decimals = 9,
+        mint::decimals = decimals,
         mint::authority = config,
     )]
     pub mint: Account<'info, Mint>,
@@ -179,7 +583,7 @@ pub struct Initialize<'info> {
 }
 
 #[derive(Accounts)]
-pub struct MintTokens<'info> {
+pub struct BurnTokens<'info> {
     #[account(
         seeds = [b"config"],
         bump = config.bump
@@ -193,94 +597,944 @@ pub struct MintTokens<'info> {
     pub mint: Account<'info, Mint>,
     
     #[account(mut)]
-    pub to: Account<'info, TokenAccount>,
+    pub from: Account<'info, TokenAccount>,
     
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct BurnTokens<'info> {
+pub struct BridgeOut<'info> {
     #[account(
         seeds = [b"config"],
         bump = config.bump
     )]
     pub config: Account<'info, OftConfig>,
-    
+
+    #[account(
+        mut,
+        seeds = [b"rate_limit"],
+        bump = rate_limit.bump,
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
     #[account(
         mut,
         address = config.mint
     )]
     pub mint: Account<'info, Mint>,
-    
+
     #[account(mut)]
     pub from: Account<'info, TokenAccount>,
-    
+
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct BridgeOut<'info> {
+pub struct InitializeRateLimit<'info> {
     #[account(
         seeds = [b"config"],
-        bump = config.bump
+        bump = config.bump,
+        has_one = authority,
     )]
     pub config: Account<'info, OftConfig>,
-    
+
     #[account(
-        mut,
-        address = config.mint
+        init,
+        payer = authority,
+        space = 8 + RateLimit::LEN,
+        seeds = [b"rate_limit"],
+        bump
     )]
-    pub mint: Account<'info, Mint>,
-    
+    pub rate_limit: Account<'info, RateLimit>,
+
     #[account(mut)]
-    pub from: Account<'info, TokenAccount>,
-    
     pub authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct BridgeIn<'info> {
+pub struct UpdateRateLimit<'info> {
     #[account(
         seeds = [b"config"],
-        bump = config.bump
+        bump = config.bump,
+        has_one = authority,
     )]
     pub config: Account<'info, OftConfig>,
-    
+
     #[account(
         mut,
-        address = config.mint
+        seeds = [b"rate_limit"],
+        bump = rate_limit.bump,
     )]
-    pub mint: Account<'info, Mint>,
-    
-    #[account(mut)]
-    pub to: Account<'info, TokenAccount>,
-    
+    pub rate_limit: Account<'info, RateLimit>,
+
     pub authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
 }
 
-// ============================================================================
-// State
-// ============================================================================
-
-#[account]
-pub struct OftConfig {
-    pub authority: Pubkey,     // 32 bytes
-    pub mint: Pubkey,          // 32 bytes
-    pub decimals: u8,          // 1 byte
-    pub bump: u8,              // 1 byte
-}
+#[derive(Accounts)]
+#[instruction(payload: Vec<u8>, signatures: Vec<GuardianSignature>, origin_token_address: [u8; 32])]
+pub struct BridgeIn<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OftConfig>,
 
-impl OftConfig {
-    pub const LEN: usize = 32 + 32 + 1 + 1; // Total: 66 bytes
-}
+    #[account(
+        seeds = [b"guardian", &config.current_guardian_index.to_le_bytes()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
 
-// ============================================================================
-// Errors
-// ============================================================================
+    #[account(
+        seeds = [b"wrapped", &BridgeMessage::peek_dst_eid(&payload).to_le_bytes(), &origin_token_address],
+        bump = wrapped_meta.bump,
+    )]
+    pub wrapped_meta: Account<'info, WrappedAssetMeta>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ConsumedMessage::LEN,
+        seeds = [b"consumed", &BridgeMessage::peek_dst_eid(&payload).to_le_bytes(), &keccak::hash(&payload).to_bytes()],
+        bump
+    )]
+    pub consumed_message: Account<'info, ConsumedMessage>,
+
+    #[account(
+        mut,
+        seeds = [b"nonce", &BridgeMessage::peek_dst_eid(&payload).to_le_bytes()],
+        bump = inbound_nonce.bump,
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub to: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(origin_eid: u32, origin_token_address: [u8; 32])]
+pub struct CreateWrapped<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + WrappedAssetMeta::LEN,
+        seeds = [b"wrapped", &origin_eid.to_le_bytes(), &origin_token_address],
+        bump
+    )]
+    pub wrapped_meta: Account<'info, WrappedAssetMeta>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_eid: u32)]
+pub struct InitializeInboundNonce<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InboundNonce::LEN,
+        seeds = [b"nonce", &chain_eid.to_le_bytes()],
+        bump
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_eid: u32)]
+pub struct ResetInboundNonce<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"nonce", &chain_eid.to_le_bytes()],
+        bump = inbound_nonce.bump,
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct CreateNftMint<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NftOrigin::LEN,
+        seeds = [b"nft_origin", &token_id.to_le_bytes()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = config,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct BridgeOutNft<'info> {
+    #[account(
+        seeds = [b"nft_origin", &token_id.to_le_bytes()],
+        bump = nft_origin.bump,
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    #[account(
+        mut,
+        address = nft_origin.local_mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub from: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(payload: Vec<u8>, signatures: Vec<GuardianSignature>, uri: String)]
+pub struct BridgeInNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        seeds = [b"guardian", &config.current_guardian_index.to_le_bytes()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        seeds = [b"nft_origin", &NftBridgeMessage::peek_token_id(&payload).to_le_bytes()],
+        bump = nft_origin.bump,
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ConsumedMessage::LEN,
+        seeds = [b"consumed", &NftBridgeMessage::peek_dst_eid(&payload).to_le_bytes(), &keccak::hash(&payload).to_bytes()],
+        bump
+    )]
+    pub consumed_message: Account<'info, ConsumedMessage>,
+
+    #[account(
+        mut,
+        seeds = [b"nonce", &NftBridgeMessage::peek_dst_eid(&payload).to_le_bytes()],
+        bump = inbound_nonce.bump,
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
+    #[account(
+        mut,
+        address = nft_origin.local_mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub to: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSchnorrConfig<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SchnorrConfig::LEN,
+        seeds = [b"schnorr_config"],
+        bump
+    )]
+    pub schnorr_config: Account<'info, SchnorrConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(payload: Vec<u8>, signature: SchnorrSignature, origin_token_address: [u8; 32])]
+pub struct BridgeInSchnorr<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        seeds = [b"schnorr_config"],
+        bump = schnorr_config.bump,
+    )]
+    pub schnorr_config: Account<'info, SchnorrConfig>,
+
+    #[account(
+        seeds = [b"wrapped", &BridgeMessage::peek_dst_eid(&payload).to_le_bytes(), &origin_token_address],
+        bump = wrapped_meta.bump,
+    )]
+    pub wrapped_meta: Account<'info, WrappedAssetMeta>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ConsumedMessage::LEN,
+        seeds = [b"consumed", &BridgeMessage::peek_dst_eid(&payload).to_le_bytes(), &keccak::hash(&payload).to_bytes()],
+        bump
+    )]
+    pub consumed_message: Account<'info, ConsumedMessage>,
+
+    #[account(
+        mut,
+        seeds = [b"nonce", &BridgeMessage::peek_dst_eid(&payload).to_le_bytes()],
+        bump = inbound_nonce.bump,
+    )]
+    pub inbound_nonce: Account<'info, InboundNonce>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub to: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGuardianSet<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianSet::LEN,
+        seeds = [b"guardian", &0u32.to_le_bytes()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGuardianSet<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, OftConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"guardian", &config.current_guardian_index.to_le_bytes()],
+        bump = old_guardian_set.bump,
+    )]
+    pub old_guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianSet::LEN,
+        seeds = [b"guardian", &(config.current_guardian_index + 1).to_le_bytes()],
+        bump
+    )]
+    pub new_guardian_set: Account<'info, GuardianSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// State
+// ============================================================================
+
+#[account]
+pub struct OftConfig {
+    pub authority: Pubkey,                    // 32 bytes
+    pub mint: Pubkey,                         // 32 bytes
+    pub decimals: u8,                         // 1 byte
+    pub current_guardian_index: u32,          // 4 bytes
+    pub verification_mode: VerificationMode,  // 1 byte
+    pub bump: u8,                             // 1 byte
+}
+
+impl OftConfig {
+    pub const LEN: usize = 32 + 32 + 1 + 4 + 1 + 1; // Total: 71 bytes
+}
+
+/// Selects which inbound-message authorization scheme `bridge_in`/
+/// `bridge_in_schnorr` enforce, chosen once by the config authority at
+/// `initialize` time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationMode {
+    GuardianMultisig,
+    Schnorr,
+}
+
+/// A Wormhole-style guardian set: the set of Ethereum-style addresses
+/// (keccak256 of an uncompressed secp256k1 pubkey, last 20 bytes) authorized
+/// to attest inbound cross-chain messages by quorum signature.
+#[account]
+pub struct GuardianSet {
+    pub index: u32,
+    pub keys: Vec<[u8; 20]>,
+    pub expiration_time: i64,
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub const MAX_GUARDIANS: usize = 19;
+    // discriminator is accounted for separately via `8 +` at each `space = ...` site.
+    pub const LEN: usize = 4 + (4 + Self::MAX_GUARDIANS * 20) + 8 + 1;
+}
+
+/// One guardian's attestation over a message hash: `r‖s‖recovery_id`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GuardianSignature {
+    pub guardian_index: u8,
+    pub signature: [u8; 65],
+}
+
+/// The fixed group public key for the Schnorr verification mode, stored as
+/// its affine x-coordinate plus a parity byte (the y-coordinate's
+/// oddness). A single aggregated signature from this key authorizes
+/// `bridge_in_schnorr`, in place of N-of-M guardian multisig.
+#[account]
+pub struct SchnorrConfig {
+    pub group_pubkey_x: [u8; 32],
+    pub parity: u8,
+    pub bump: u8,
+}
+
+impl SchnorrConfig {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+
+/// A Schnorr signature `(R_x, s)` over the secp256k1 group, verified
+/// against a [`SchnorrConfig`]'s fixed group key by [`verify_schnorr_signature`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SchnorrSignature {
+    pub r_x: [u8; 32],
+    pub s: [u8; 32],
+}
+
+/// The cryptographically-bound payload behind a `bridge_in` call:
+/// `[version(1), dst_eid(4), nonce(8), recipient(32), amount(8)]`. Signed
+/// over as a whole so a valid guardian signature set can't be replayed
+/// against a different amount or recipient.
+pub struct BridgeMessage {
+    pub version: u8,
+    pub dst_eid: u32,
+    pub nonce: u64,
+    pub recipient: [u8; 32],
+    pub amount: u64,
+}
+
+impl BridgeMessage {
+    pub const LEN: usize = 1 + 4 + 8 + 32 + 8;
+
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(data.len() == Self::LEN, OftError::InvalidPayload);
+
+        let mut recipient = [0u8; 32];
+        recipient.copy_from_slice(&data[13..45]);
+
+        Ok(Self {
+            version: data[0],
+            dst_eid: u32::from_be_bytes(data[1..5].try_into().unwrap()),
+            nonce: u64::from_be_bytes(data[5..13].try_into().unwrap()),
+            recipient,
+            amount: u64::from_be_bytes(data[45..53].try_into().unwrap()),
+        })
+    }
+
+    /// Reads just the `dst_eid` field, for use in account-seed derivation
+    /// before the payload has been through full [`decode`] validation.
+    /// Payloads too short to hold the field map to `u32::MAX`, which won't match any
+    /// real `InboundNonce`/`ConsumedMessage` PDA, so the instruction fails
+    /// via Anchor's seed-mismatch check rather than panicking.
+    pub fn peek_dst_eid(data: &[u8]) -> u32 {
+        data.get(1..5)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_be_bytes)
+            .unwrap_or(u32::MAX)
+    }
+}
+
+/// Records the canonical origin of a wrapped asset bridged into Solana, so
+/// front-ends/relayers can answer "what remote-chain token does this local
+/// mint represent" instead of only seeing an undifferentiated EAGLE mint.
+/// `local_mint` is always the program's single EAGLE mint (`OftConfig::mint`)
+/// — this program doesn't mint a distinct SPL token per origin asset.
+/// `bridge_in`/`bridge_in_schnorr` require a registered entry for the
+/// payload's EID and `origin_token_address`, but do not branch on its fields.
+#[account]
+pub struct WrappedAssetMeta {
+    pub origin_eid: u32,
+    pub origin_token_address: [u8; 32],
+    pub local_mint: Pubkey,
+    pub is_native: bool,
+    pub bump: u8,
+}
+
+impl WrappedAssetMeta {
+    pub const LEN: usize = 4 + 32 + 32 + 1 + 1;
+}
+
+/// A refilling token bucket gating `bridge_out`, so a compromised authority
+/// or buggy relayer can drain at most `capacity` raw units before needing to
+/// wait for the bucket to refill at `refill_per_second`.
+#[account]
+pub struct RateLimit {
+    pub capacity: u64,
+    pub refill_per_second: u64,
+    pub available: u64,
+    pub last_refill: i64,
+    pub bump: u8,
+}
+
+impl RateLimit {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 1;
+}
+
+/// Marker account for a single processed `bridge_in` payload, seeded by
+/// `(dst_eid, keccak256(payload))`. Its existence is the replay guard:
+/// `init` fails with an account-already-exists error if the same payload
+/// hash is ever submitted again for that chain.
+#[account]
+pub struct ConsumedMessage {
+    pub bump: u8,
+}
+
+impl ConsumedMessage {
+    pub const LEN: usize = 1;
+}
+
+/// Tracks the highest-processed nonce for a given remote chain, mirroring
+/// LayerZero's per-pathway nonce ordering.
+#[account]
+pub struct InboundNonce {
+    pub chain_eid: u32,
+    pub highest_processed_nonce: u64,
+    pub bump: u8,
+}
+
+impl InboundNonce {
+    pub const LEN: usize = 4 + 8 + 1;
+}
+
+/// Records the canonical origin chain and local mint for an EAGLE
+/// collectible, keyed by its `token_id`. `origin_chain_id` is set once at
+/// `create_nft_mint` time and never changes, so a collectible bridged
+/// Ethereum -> Solana -> Ethereum always resolves to the same mint instead
+/// of minting a duplicate.
+#[account]
+pub struct NftOrigin {
+    pub token_id: u64,
+    pub origin_chain_id: u32,
+    pub local_mint: Pubkey,
+    pub bump: u8,
+}
+
+impl NftOrigin {
+    pub const LEN: usize = 8 + 4 + 32 + 1;
+}
+
+/// The cryptographically-bound payload behind a `bridge_in_nft` call:
+/// `[version(1), token_id(8), dst_eid(4), nonce(8), recipient(32),
+/// metadata_uri_hash(32)]`, mirroring [`BridgeMessage`]'s fixed-layout
+/// style but carrying per-token identity and a metadata hash instead of an
+/// amount.
+pub struct NftBridgeMessage {
+    pub version: u8,
+    pub token_id: u64,
+    pub dst_eid: u32,
+    pub nonce: u64,
+    pub recipient: [u8; 32],
+    pub metadata_uri_hash: [u8; 32],
+}
+
+impl NftBridgeMessage {
+    pub const LEN: usize = 1 + 8 + 4 + 8 + 32 + 32;
+
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(data.len() == Self::LEN, OftError::InvalidPayload);
+
+        let mut recipient = [0u8; 32];
+        recipient.copy_from_slice(&data[21..53]);
+        let mut metadata_uri_hash = [0u8; 32];
+        metadata_uri_hash.copy_from_slice(&data[53..85]);
+
+        Ok(Self {
+            version: data[0],
+            token_id: u64::from_be_bytes(data[1..9].try_into().unwrap()),
+            dst_eid: u32::from_be_bytes(data[9..13].try_into().unwrap()),
+            nonce: u64::from_be_bytes(data[13..21].try_into().unwrap()),
+            recipient,
+            metadata_uri_hash,
+        })
+    }
+
+    /// Reads just the `dst_eid` field, for use in account-seed derivation
+    /// before the payload has been through full [`decode`] validation.
+    pub fn peek_dst_eid(data: &[u8]) -> u32 {
+        data.get(9..13)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_be_bytes)
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Reads just the `token_id` field, for use in account-seed derivation
+    /// before the payload has been through full [`decode`] validation.
+    pub fn peek_token_id(data: &[u8]) -> u64 {
+        data.get(1..9)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_be_bytes)
+            .unwrap_or(u64::MAX)
+    }
+}
+
+/// Encodes the message a guardian set signs off on to authorize rotating to
+/// `new_index`/`new_keys`/`new_expiration_time`, in [`BridgeMessage`]'s
+/// big-endian style: `new_index(4) ++ key_count(1) ++ keys(20 each) ++
+/// new_expiration_time(8)`.
+fn encode_guardian_set_update(new_index: u32, new_keys: &[[u8; 20]], new_expiration_time: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(4 + 1 + new_keys.len() * 20 + 8);
+    message.extend_from_slice(&new_index.to_be_bytes());
+    message.push(new_keys.len() as u8);
+    for key in new_keys {
+        message.extend_from_slice(key);
+    }
+    message.extend_from_slice(&new_expiration_time.to_be_bytes());
+    message
+}
+
+/// Verifies that `signatures` contains enough distinct, ascending-order,
+/// valid guardian attestations over `message_hash` to reach quorum
+/// (`floor(n*2/3)+1`) for `guardian_set`.
+fn verify_guardian_quorum(
+    guardian_set: &GuardianSet,
+    message_hash: &[u8; 32],
+    signatures: &[GuardianSignature],
+) -> Result<()> {
+    let quorum = guardian_set.keys.len() * 2 / 3 + 1;
+    require!(signatures.len() >= quorum, OftError::QuorumNotMet);
+
+    let mut last_index: Option<u8> = None;
+    for sig in signatures.iter() {
+        if let Some(last) = last_index {
+            require!(sig.guardian_index > last, OftError::SignaturesOutOfOrder);
+        }
+        last_index = Some(sig.guardian_index);
+
+        let guardian_key = guardian_set
+            .keys
+            .get(sig.guardian_index as usize)
+            .ok_or(OftError::InvalidGuardianIndex)?;
+
+        let recovery_id = sig.signature[64];
+        let recovered_pubkey = secp256k1_recover(message_hash, recovery_id, &sig.signature[..64])
+            .map_err(|_| OftError::InvalidSignature)?;
+        let recovered_address = keccak::hash(&recovered_pubkey.to_bytes()).to_bytes();
+
+        require!(&recovered_address[12..32] == guardian_key, OftError::InvalidSignature);
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// secp256k1 scalar arithmetic, for Schnorr verification via the `ecrecover`
+// trick below. There's no bignum crate in this tree, so this hand-rolls just
+// the handful of mod-`n` operations the trick needs (mirroring `fast.rs`'s
+// hand-rolled Keccak permutation elsewhere in this workspace), represented
+// as big-endian `[u64; 4]` limb arrays.
+// ----------------------------------------------------------------------------
+
+/// The secp256k1 base point order `n`, big-endian.
+const SECP256K1_ORDER: [u64; 4] = [
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFFFFFFFFFE,
+    0xBAAEDCE6AF48A03B,
+    0xBFD25E8CD0364141,
+];
+
+/// `2^256 mod n`. `add_mod` uses this to correct for the rare case where a
+/// 256-bit addition overflows past the register width, without needing
+/// general multi-limb division.
+const OVERFLOW_CORRECTION: [u64; 4] = [
+    0x0000000000000000,
+    0x0000000000000001,
+    0x4551231950B75FC4,
+    0x402DA1732FC9BEBF,
+];
+
+fn be_bytes_to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        *limb = u64::from_be_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap());
+    }
+    limbs
+}
+
+fn limbs_to_be_bytes(limbs: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, limb) in limbs.iter().enumerate() {
+        bytes[i * 8..i * 8 + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+fn limbs_ge(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in 0..4 {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+/// `a - b`, assuming `a >= b`.
+fn limbs_sub(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut result = [0u64; 4];
+    let mut borrow = 0i128;
+    for i in (0..4).rev() {
+        let diff = a[i] as i128 - b[i] as i128 - borrow;
+        if diff < 0 {
+            result[i] = (diff + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            result[i] = diff as u64;
+            borrow = 0;
+        }
+    }
+    result
+}
+
+/// `(a + b) mod n`, for `a, b < n`.
+fn add_mod(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut sum = [0u64; 4];
+    let mut carry = 0u128;
+    for i in (0..4).rev() {
+        let s = a[i] as u128 + b[i] as u128 + carry;
+        sum[i] = s as u64;
+        carry = s >> 64;
+    }
+    if carry != 0 {
+        let mut carry2 = 0u128;
+        for i in (0..4).rev() {
+            let s = sum[i] as u128 + OVERFLOW_CORRECTION[i] as u128 + carry2;
+            sum[i] = s as u64;
+            carry2 = s >> 64;
+        }
+    } else if limbs_ge(&sum, &SECP256K1_ORDER) {
+        sum = limbs_sub(&sum, &SECP256K1_ORDER);
+    }
+    sum
+}
+
+/// `(a * b) mod n`, via double-and-add over `b`'s 256 bits. Avoids needing
+/// general multi-limb division to reduce a 512-bit product.
+fn mul_mod(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut result = [0u64; 4];
+    for &limb in b.iter() {
+        for bit in (0..64).rev() {
+            result = add_mod(&result, &result);
+            if (limb >> bit) & 1 == 1 {
+                result = add_mod(&result, a);
+            }
+        }
+    }
+    result
+}
+
+/// `(n - a) mod n`, for `a < n`; `0` maps to `0`, not `n`.
+fn neg_mod(a: &[u64; 4]) -> [u64; 4] {
+    if *a == [0u64; 4] {
+        [0u64; 4]
+    } else {
+        limbs_sub(&SECP256K1_ORDER, a)
+    }
+}
+
+/// Verifies a single aggregated Schnorr signature `(r_x, s)` over secp256k1
+/// against `config`'s fixed group public key, for message hash
+/// `message_hash`, using the standard "ecrecover trick": ECDSA pubkey
+/// recovery solves `Q = r'^-1 * (s'*R' - z*G)` for an unknown point `Q`
+/// given a known point `R'` and scalars `r', s', z`. Playing the group
+/// public key `P` in `R'`'s role and choosing `z = -(s*px) mod n`,
+/// `s' = -(c*px) mod n` (where `c` is the Schnorr challenge and `px` is
+/// `P`'s x-coordinate) makes the recovered `Q` equal the signature's
+/// claimed nonce point `R = s*G - c*P`. Unlike Ethereum's `ecrecover`
+/// (which only returns an address), Solana's `secp256k1_recover` returns
+/// the full uncompressed point, so `R`'s x-coordinate can be compared
+/// directly instead of hashing both sides down to an address first.
+fn verify_schnorr_signature(
+    config: &SchnorrConfig,
+    message_hash: &[u8; 32],
+    signature: &SchnorrSignature,
+) -> Result<()> {
+    // Challenge: c = keccak256(R_x || parity || group_pubkey_x || m),
+    // reduced into the scalar field before use below.
+    let mut challenge_input = Vec::with_capacity(32 + 1 + 32 + 32);
+    challenge_input.extend_from_slice(&signature.r_x);
+    challenge_input.push(config.parity);
+    challenge_input.extend_from_slice(&config.group_pubkey_x);
+    challenge_input.extend_from_slice(message_hash);
+    let c = keccak::hash(&challenge_input).to_bytes();
+
+    let px = be_bytes_to_limbs(&config.group_pubkey_x);
+    let mut c_scalar = be_bytes_to_limbs(&c);
+    // A 256-bit hash is below 2n, so one subtraction reduces it mod n.
+    if limbs_ge(&c_scalar, &SECP256K1_ORDER) {
+        c_scalar = limbs_sub(&c_scalar, &SECP256K1_ORDER);
+    }
+    let s_scalar = be_bytes_to_limbs(&signature.s);
+
+    let z = limbs_to_be_bytes(&neg_mod(&mul_mod(&s_scalar, &px)));
+    let s_prime = limbs_to_be_bytes(&neg_mod(&mul_mod(&c_scalar, &px)));
+
+    let mut recovery_signature = [0u8; 64];
+    recovery_signature[..32].copy_from_slice(&config.group_pubkey_x);
+    recovery_signature[32..].copy_from_slice(&s_prime);
+
+    let recovered = secp256k1_recover(&z, config.parity, &recovery_signature)
+        .map_err(|_| OftError::InvalidSchnorrSignature)?;
+
+    require!(
+        recovered.to_bytes()[0..32] == signature.r_x,
+        OftError::InvalidSchnorrSignature
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct WrappedAssetBridgedIn {
+    pub origin_eid: u32,
+    pub origin_token_address: [u8; 32],
+    pub local_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct NftBridgedOut {
+    pub token_id: u64,
+    pub origin_chain_id: u32,
+    pub destination_chain_id: u32,
+    pub recipient: [u8; 32],
+    pub metadata_uri_hash: [u8; 32],
+}
+
+#[event]
+pub struct NftBridgedIn {
+    pub token_id: u64,
+    pub origin_chain_id: u32,
+    pub local_mint: Pubkey,
+    pub uri: String,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
 
 #[error_code]
 pub enum OftError {
@@ -292,5 +1546,50 @@ pub enum OftError {
     
     #[msg("Invalid chain ID")]
     InvalidChainId,
+
+    #[msg("Guardian set has no guardians")]
+    NoGuardians,
+
+    #[msg("Too many guardians for a single set")]
+    TooManyGuardians,
+
+    #[msg("Guardian set has expired")]
+    GuardianSetExpired,
+
+    #[msg("Not enough valid guardian signatures to reach quorum")]
+    QuorumNotMet,
+
+    #[msg("Guardian signatures must be in strictly ascending index order")]
+    SignaturesOutOfOrder,
+
+    #[msg("Signature references a guardian index outside the current set")]
+    InvalidGuardianIndex,
+
+    #[msg("Guardian signature does not recover to the expected guardian key")]
+    InvalidSignature,
+
+    #[msg("Malformed bridge payload")]
+    InvalidPayload,
+
+    #[msg("Payload recipient does not match the destination token account")]
+    RecipientMismatch,
+
+    #[msg("Amount exceeds the available outbound rate-limit bucket")]
+    RateLimitExceeded,
+
+    #[msg("Hashed URI does not match the metadata hash carried in the bridge payload")]
+    MetadataHashMismatch,
+
+    #[msg("Schnorr signature does not recover to the expected nonce point")]
+    InvalidSchnorrSignature,
+
+    #[msg("Instruction does not match the configured verification mode")]
+    WrongVerificationMode,
+}
+
+#[error_code]
+pub enum ReplayError {
+    #[msg("Message nonce has already been processed")]
+    NonceAlreadyProcessed,
 }
 
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
+use anchor_lang::system_program::{self, Transfer};
 use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Burn};
 
 declare_id!("EjpziSWGRcEiDHLXft5etbUtcJiZxEttkwz1tqiuzzWU");
@@ -21,6 +24,9 @@ pub const MSG_TYPE_SEND_AND_CALL: u8 = 1;
 /// Decimals conversion: ETH (18) -> SOL (9)
 pub const SHARED_DECIMALS: u8 = 9;
 
+/// Maximum number of registered DVN verifier addresses in a [`DvnSet`].
+pub const MAX_DVNS: usize = 16;
+
 #[program]
 pub mod eagle_oft_layerzero {
     use super::*;
@@ -30,23 +36,61 @@ pub mod eagle_oft_layerzero {
         ctx: Context<Initialize>,
         endpoint_program: Pubkey,
         admin: Pubkey,
+        local_decimals: u8,
+        fee_collector: Pubkey,
     ) -> Result<()> {
+        // Shared-decimal amounts are scaled back up by `10^(local-shared)`,
+        // so local precision can't be coarser than the wire format.
+        require!(local_decimals >= SHARED_DECIMALS, OftError::InvalidLocalDecimals);
+        let decimal_conversion_rate = 10u64
+            .checked_pow((local_decimals - SHARED_DECIMALS) as u32)
+            .ok_or(OftError::Overflow)?;
+
         let config = &mut ctx.accounts.oft_config;
-        
+
         config.admin = admin;
         config.mint = ctx.accounts.mint.key();
         config.endpoint_program = endpoint_program;
         config.paused = false;
         config.total_bridged_in = 0;
         config.total_bridged_out = 0;
+        config.local_decimals = local_decimals;
+        config.decimal_conversion_rate = decimal_conversion_rate;
+        config.fee_collector = fee_collector;
+        config.total_fees_collected = 0;
         config.bump = ctx.bumps.oft_config;
-        
+
         msg!("✅ EAGLE OFT LayerZero initialized");
         msg!("   Admin: {}", admin);
         msg!("   Mint: {}", config.mint);
         msg!("   Endpoint: {}", endpoint_program);
         msg!("   Solana EID: {}", SOLANA_EID);
-        
+        msg!("   Local decimals: {} (shared: {})", local_decimals, SHARED_DECIMALS);
+        msg!("   Fee collector: {}", fee_collector);
+
+        Ok(())
+    }
+
+    /// Records this OFT's canonical origin: the EID and token address its
+    /// supply is actually backed by. A natively-Solana OFT registers
+    /// `SOLANA_EID` and its own mint here. `send` and `lz_receive` both
+    /// require this account, so they fail until it has been registered.
+    pub fn register_origin(
+        ctx: Context<RegisterOrigin>,
+        origin_eid: u32,
+        origin_token_address: [u8; 32],
+        origin_decimals: u8,
+    ) -> Result<()> {
+        let wrapped_meta = &mut ctx.accounts.wrapped_meta;
+
+        wrapped_meta.mint = ctx.accounts.mint.key();
+        wrapped_meta.origin_eid = origin_eid;
+        wrapped_meta.origin_token_address = origin_token_address;
+        wrapped_meta.origin_decimals = origin_decimals;
+        wrapped_meta.bump = ctx.bumps.wrapped_meta;
+
+        msg!("✅ Origin registered: EID {} token {:?}", origin_eid, origin_token_address);
+
         Ok(())
     }
 
@@ -61,6 +105,7 @@ pub mod eagle_oft_layerzero {
         peer_info.eid = dst_eid;
         peer_info.address = peer;
         peer_info.enabled = true;
+        peer_info.max_received_nonce = 0;
         peer_info.bump = ctx.bumps.peer_config;
         
         msg!("✅ Peer set for EID {}", dst_eid);
@@ -77,14 +122,56 @@ pub mod eagle_oft_layerzero {
     ) -> Result<SendReceipt> {
         let config = &mut ctx.accounts.oft_config;
         let peer = &ctx.accounts.peer_config;
-        
+
         require!(!config.paused, OftError::Paused);
+        require!(peer.eid == send_param.dst_eid, OftError::InvalidPeer);
         require!(peer.enabled, OftError::PeerDisabled);
+
+        // Quote and collect the cross-chain messaging fee up front, so a
+        // request that can't afford it fails before any tokens are burned.
+        let message_size = estimate_message_size(&send_param.compose_msg);
+        let quote = quote_via_endpoint(
+            &ctx.accounts.endpoint_program,
+            send_param.dst_eid,
+            message_size,
+            ctx.accounts.dvn_set.verifiers.len() as u8,
+        )
+        .unwrap_or_else(|| fallback_quote(message_size));
+
+        require!(send_param.max_fee >= quote.native_fee, OftError::FeeTooLow);
         require!(
-            send_param.amount_ld >= send_param.min_amount_ld,
+            ctx.accounts.fee_collector.key() == config.fee_collector,
+            OftError::FeeCollectorMismatch
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sender.to_account_info(),
+                    to: ctx.accounts.fee_collector.to_account_info(),
+                },
+            ),
+            quote.native_fee,
+        )?;
+
+        config.total_fees_collected = config.total_fees_collected
+            .checked_add(quote.native_fee)
+            .ok_or(OftError::Overflow)?;
+
+        // The wire message only carries shared-decimal precision, so floor
+        // `amount_ld` to a whole multiple of the conversion rate first. The
+        // remainder ("dust") is simply never burned, leaving it in the
+        // sender's account rather than stranding it in the bridge.
+        let rate = config.decimal_conversion_rate;
+        let dust = send_param.amount_ld % rate;
+        let clean_amount_ld = send_param.amount_ld - dust;
+
+        require!(
+            clean_amount_ld >= send_param.min_amount_ld,
             OftError::SlippageExceeded
         );
-        
+
         // Burn tokens from sender
         token::burn(
             CpiContext::new(
@@ -95,29 +182,53 @@ pub mod eagle_oft_layerzero {
                     authority: ctx.accounts.sender.to_account_info(),
                 },
             ),
-            send_param.amount_ld,
+            clean_amount_ld,
         )?;
 
         // Update stats
         config.total_bridged_out = config.total_bridged_out
-            .checked_add(send_param.amount_ld)
+            .checked_add(clean_amount_ld)
             .ok_or(OftError::Overflow)?;
 
-        // Encode OFT message
+        if dust > 0 {
+            emit!(DustRemoved {
+                sender: ctx.accounts.sender.key(),
+                amount_ld: send_param.amount_ld,
+                clean_amount_ld,
+                dust,
+            });
+        }
+
+        let amount_sd = clean_amount_ld / rate;
+
+        // Encode OFT message. A non-empty compose_msg switches this to a
+        // SEND_AND_CALL message, appending the Solana sender and the compose
+        // payload after the plain-transfer header so the remote lz_receive
+        // can CPI into the recipient program once minting completes.
+        let msg_type = if send_param.compose_msg.is_empty() {
+            MSG_TYPE_SEND
+        } else {
+            MSG_TYPE_SEND_AND_CALL
+        };
         let message = encode_oft_message(
-            MSG_TYPE_SEND,
+            msg_type,
             send_param.to,
-            send_param.amount_ld,
+            amount_sd,
+            if msg_type == MSG_TYPE_SEND_AND_CALL {
+                Some((ctx.accounts.sender.key(), send_param.compose_msg.as_slice()))
+            } else {
+                None
+            },
         );
-        
+
         // Generate GUID
         let guid = generate_guid(
             ctx.accounts.sender.key(),
             send_param.dst_eid,
-            send_param.amount_ld,
+            amount_sd,
             Clock::get()?.unix_timestamp as u64,
         );
-        
+
         // In full LayerZero implementation, we would CPI to endpoint here:
         // lz_endpoint::cpi::send(
         //     CpiContext::new(
@@ -126,25 +237,26 @@ pub mod eagle_oft_layerzero {
         //     ),
         //     SendParams { dst_eid, to, amount, options, ... }
         // )?;
-        
+
         // For now, emit event for DVNs to pick up
         emit!(SendEvent {
             guid,
             src_eid: SOLANA_EID,
             dst_eid: send_param.dst_eid,
             to: send_param.to,
-            amount_ld: send_param.amount_ld,
+            amount_ld: clean_amount_ld,
             sender: ctx.accounts.sender.key(),
+            origin_eid: ctx.accounts.wrapped_meta.origin_eid,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("🚀 Sent {} tokens to EID {} (GUID: {:?})", 
-            send_param.amount_ld, send_param.dst_eid, guid);
-        
+        msg!("🚀 Sent {} tokens to EID {} (GUID: {:?})",
+            clean_amount_ld, send_param.dst_eid, guid);
+
         Ok(SendReceipt {
             guid,
             nonce: config.total_bridged_out, // Use as nonce
-            fee: MessagingFee { native_fee: 0, lz_token_fee: 0 },
+            fee: quote,
         })
     }
 
@@ -157,42 +269,68 @@ pub mod eagle_oft_layerzero {
         message: Vec<u8>,
         executor: Pubkey,
         extra_data: Vec<u8>,
+        dvn_signatures: Vec<DvnSignature>,
     ) -> Result<()> {
         let config = &mut ctx.accounts.oft_config;
-        let peer = &ctx.accounts.peer_config;
-        
+        let peer = &mut ctx.accounts.peer_config;
+
         require!(!config.paused, OftError::Paused);
         require!(peer.enabled, OftError::PeerDisabled);
-        
+
         // Verify message comes from our peer
         require!(peer.eid == origin.src_eid, OftError::InvalidPeer);
         require!(peer.address == origin.sender, OftError::InvalidPeer);
-        
+
+        // Reject a regressed/replayed nonce. The `claim` account below is
+        // the hard replay guard (its `init` fails outright on redelivery of
+        // the exact same `(src_eid, nonce)`); this additionally stops an
+        // executor from re-driving an *older* message than one already seen.
+        require!(origin.nonce > peer.max_received_nonce, OftError::NonceRegressed);
+        peer.max_received_nonce = origin.nonce;
+        ctx.accounts.claim.bump = ctx.bumps.claim;
+
+        // Require a DVN quorum attestation over this exact message before
+        // minting anything. `endpoint_program` being the signer only proves
+        // *something* relayed this call; it's the DVN signatures that prove
+        // a threshold of independent verifiers actually agreed on it.
+        let dvn_digest = compute_dvn_digest(origin.src_eid, &origin.sender, origin.nonce, &message);
+        verify_dvn_quorum(&ctx.accounts.dvn_set, &dvn_digest, &dvn_signatures)?;
+
         // Decode OFT message
-        let (msg_type, to_address, amount_ld) = decode_oft_message(&message)?;
-        require!(msg_type == MSG_TYPE_SEND, OftError::InvalidMessageType);
-        
+        let decoded = decode_oft_message(&message)?;
+        require!(
+            decoded.msg_type == MSG_TYPE_SEND || decoded.msg_type == MSG_TYPE_SEND_AND_CALL,
+            OftError::InvalidMessageType
+        );
+
         // Convert bytes32 to Solana Pubkey
-        let recipient = Pubkey::new_from_array(to_address);
-        
+        let recipient = Pubkey::new_from_array(decoded.to);
+
         // Verify recipient token account matches
         require!(
             ctx.accounts.to.owner == recipient,
             OftError::InvalidRecipient
         );
-        
+
+        // The wire amount is in shared decimals; scale back up to this
+        // mint's local decimals before minting.
+        let amount_ld = decoded
+            .amount_ld
+            .checked_mul(config.decimal_conversion_rate)
+            .ok_or(OftError::Overflow)?;
+
         // Update stats
         config.total_bridged_in = config.total_bridged_in
             .checked_add(amount_ld)
             .ok_or(OftError::Overflow)?;
-        
+
         // Mint tokens to recipient
         let seeds = &[
             b"oft_config",
             &[config.bump],
         ];
         let signer = &[&seeds[..]];
-        
+
         token::mint_to(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -206,6 +344,26 @@ pub mod eagle_oft_layerzero {
             amount_ld,
         )?;
 
+        // SEND_AND_CALL: after minting, CPI into the recipient program so it
+        // can react to the transfer, passing along who initiated it on the
+        // source chain. The compose target program is expected as
+        // `remaining_accounts[0]`, with any accounts it needs following.
+        if decoded.msg_type == MSG_TYPE_SEND_AND_CALL {
+            let sender = decoded.sender.ok_or(OftError::InvalidMessage)?;
+            require!(!ctx.remaining_accounts.is_empty(), OftError::MissingComposeProgram);
+            let compose_program = &ctx.remaining_accounts[0];
+            let compose_accounts = &ctx.remaining_accounts[1..];
+            invoke_compose(
+                compose_program,
+                compose_accounts,
+                origin.src_eid,
+                sender,
+                guid,
+                amount_ld,
+                &decoded.compose_msg,
+            )?;
+        }
+
         emit!(ReceiveEvent {
             guid,
             src_eid: origin.src_eid,
@@ -213,43 +371,34 @@ pub mod eagle_oft_layerzero {
             to: recipient,
             amount_ld,
             nonce: origin.nonce,
+            sender: decoded.sender,
+            origin_eid: ctx.accounts.wrapped_meta.origin_eid,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("📥 Received {} tokens from EID {} for {} (GUID: {:?})", 
+        msg!("📥 Received {} tokens from EID {} for {} (GUID: {:?})",
             amount_ld, origin.src_eid, recipient, guid);
-        
+
         Ok(())
     }
 
     /// Quote the fee for sending tokens cross-chain
     pub fn quote_send(
-        _ctx: Context<QuoteSend>,
+        ctx: Context<QuoteSend>,
         send_param: SendParam,
         _pay_in_lz_token: bool,
     ) -> Result<MessagingFee> {
-        // In full implementation, this would CPI to LayerZero endpoint
-        // to get real-time fee quote based on:
-        // - Destination chain
-        // - Message size
-        // - DVN configuration
-        // - Gas prices
-        
-        // Estimated fees for Solana -> Ethereum:
-        // - DVN verification: ~0.0005 SOL per DVN (need 2+ DVNs)
-        // - Executor gas on Ethereum: ~0.001 SOL equivalent
-        // Total: ~0.002-0.005 SOL
-        
-        let base_fee = 2_000_000; // 0.002 SOL in lamports
-        let per_byte_fee = 100; // Small fee per byte
-        
-        let message_size = 1 + 32 + 8; // msgType + to + amount
-        let native_fee = base_fee + (message_size * per_byte_fee);
-        
-        Ok(MessagingFee {
-            native_fee,
-            lz_token_fee: 0, // Not using LZ token payment
-        })
+        let message_size = estimate_message_size(&send_param.compose_msg);
+
+        let quote = quote_via_endpoint(
+            &ctx.accounts.endpoint_program,
+            send_param.dst_eid,
+            message_size,
+            ctx.accounts.dvn_set.verifiers.len() as u8,
+        )
+        .unwrap_or_else(|| fallback_quote(message_size));
+
+        Ok(quote)
     }
 
     /// Emergency pause/unpause
@@ -289,7 +438,49 @@ pub mod eagle_oft_layerzero {
         config.admin = new_admin;
         
         msg!("👑 Admin transferred from {} to {}", old_admin, new_admin);
-        
+
+        Ok(())
+    }
+
+    /// One-time registration of the DVN set `lz_receive` verifies signatures
+    /// against. Mirrors `set_peer`: a single PDA created once, with no
+    /// re-initialization path other than `update_dvn_threshold`.
+    pub fn set_dvn_set(
+        ctx: Context<SetDvnSet>,
+        verifiers: Vec<[u8; 20]>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            !verifiers.is_empty() && verifiers.len() <= MAX_DVNS,
+            OftError::InvalidDvnSet
+        );
+        require!(
+            threshold >= 1 && threshold as usize <= verifiers.len(),
+            OftError::InvalidDvnSet
+        );
+
+        let dvn_set = &mut ctx.accounts.dvn_set;
+        dvn_set.admin = ctx.accounts.admin.key();
+        dvn_set.threshold = threshold;
+        dvn_set.verifiers = verifiers;
+        dvn_set.bump = ctx.bumps.dvn_set;
+
+        msg!("✅ DVN set registered: {} verifiers, threshold {}", dvn_set.verifiers.len(), threshold);
+
+        Ok(())
+    }
+
+    /// Adjusts the quorum threshold for the existing DVN set.
+    pub fn update_dvn_threshold(ctx: Context<UpdateDvnThreshold>, threshold: u8) -> Result<()> {
+        let dvn_set = &mut ctx.accounts.dvn_set;
+        require!(
+            threshold >= 1 && threshold as usize <= dvn_set.verifiers.len(),
+            OftError::InvalidDvnSet
+        );
+        dvn_set.threshold = threshold;
+
+        msg!("🔧 DVN threshold updated to {}", threshold);
+
         Ok(())
     }
 }
@@ -299,6 +490,7 @@ pub mod eagle_oft_layerzero {
 // ============================================================================
 
 #[derive(Accounts)]
+#[instruction(endpoint_program: Pubkey, admin: Pubkey, local_decimals: u8)]
 pub struct Initialize<'info> {
     #[account(
         init,
@@ -308,11 +500,11 @@ pub struct Initialize<'info> {
         bump
     )]
     pub oft_config: Account<'info, OftConfig>,
-    
+
     #[account(
         init,
         payer = payer,
-        mint::decimals = SHARED_DECIMALS,
+        mint::decimals = local_decimals,
         mint::authority = oft_config,
     )]
     pub mint: Account<'info, Mint>,
@@ -325,6 +517,33 @@ pub struct Initialize<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct RegisterOrigin<'info> {
+    #[account(
+        seeds = [b"oft_config"],
+        bump = oft_config.bump,
+        has_one = admin,
+        has_one = mint,
+    )]
+    pub oft_config: Account<'info, OftConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + WrappedMeta::INIT_SPACE,
+        seeds = [b"wrapped_meta", mint.key().as_ref()],
+        bump
+    )]
+    pub wrapped_meta: Account<'info, WrappedMeta>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(dst_eid: u32)]
 pub struct SetPeer<'info> {
@@ -351,6 +570,7 @@ pub struct SetPeer<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(send_param: SendParam)]
 pub struct Send<'info> {
     #[account(
         mut,
@@ -358,9 +578,9 @@ pub struct Send<'info> {
         bump = oft_config.bump
     )]
     pub oft_config: Account<'info, OftConfig>,
-    
+
     #[account(
-        seeds = [b"peer", &oft_config.bump.to_le_bytes()], // Will be validated in instruction
+        seeds = [b"peer", &send_param.dst_eid.to_le_bytes()],
         bump = peer_config.bump
     )]
     pub peer_config: Account<'info, PeerConfig>,
@@ -370,18 +590,39 @@ pub struct Send<'info> {
         address = oft_config.mint
     )]
     pub mint: Account<'info, Mint>,
-    
+
+    #[account(
+        seeds = [b"wrapped_meta", mint.key().as_ref()],
+        bump = wrapped_meta.bump
+    )]
+    pub wrapped_meta: Account<'info, WrappedMeta>,
+
     #[account(
         mut,
         constraint = from.mint == mint.key(),
         constraint = from.owner == sender.key()
     )]
     pub from: Account<'info, TokenAccount>,
-    
+
+    #[account(mut)]
     pub sender: Signer<'info>,
-    
+
+    #[account(
+        seeds = [b"dvn_set"],
+        bump = dvn_set.bump
+    )]
+    pub dvn_set: Account<'info, DvnSet>,
+
+    /// The endpoint-owned fee collector. CHECK'd in the instruction body
+    /// against `oft_config.fee_collector` (raised as `FeeCollectorMismatch`
+    /// rather than Anchor's generic address-constraint error).
+    /// CHECK: validated in the instruction body
+    #[account(mut)]
+    pub fee_collector: AccountInfo<'info>,
+
     pub token_program: Program<'info, Token>,
-    
+    pub system_program: Program<'info, System>,
+
     /// LayerZero endpoint program
     /// CHECK: Validated against oft_config.endpoint_program
     #[account(address = oft_config.endpoint_program)]
@@ -389,6 +630,7 @@ pub struct Send<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(origin: Origin)]
 pub struct LzReceive<'info> {
     #[account(
         mut,
@@ -396,31 +638,59 @@ pub struct LzReceive<'info> {
         bump = oft_config.bump
     )]
     pub oft_config: Account<'info, OftConfig>,
-    
+
     #[account(
+        mut,
         seeds = [b"peer", &peer_config.eid.to_le_bytes()],
         bump = peer_config.bump
     )]
     pub peer_config: Account<'info, PeerConfig>,
-    
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Claim::INIT_SPACE,
+        seeds = [b"claim", &origin.src_eid.to_le_bytes(), &origin.nonce.to_le_bytes()],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        seeds = [b"dvn_set"],
+        bump = dvn_set.bump
+    )]
+    pub dvn_set: Account<'info, DvnSet>,
+
     #[account(
         mut,
         address = oft_config.mint
     )]
     pub mint: Account<'info, Mint>,
-    
+
+    #[account(
+        seeds = [b"wrapped_meta", mint.key().as_ref()],
+        bump = wrapped_meta.bump
+    )]
+    pub wrapped_meta: Account<'info, WrappedMeta>,
+
     #[account(
         mut,
         constraint = to.mint == mint.key()
     )]
     pub to: Account<'info, TokenAccount>,
-    
+
     /// LayerZero endpoint program (only endpoint can call lz_receive)
     /// CHECK: Must be oft_config.endpoint_program
     #[account(address = oft_config.endpoint_program)]
     pub endpoint_program: Signer<'info>,
-    
+
+    /// Pays for the `claim` PDA's rent. In production this is typically the
+    /// executor fronting the delivery; CHECK: only used as a fee payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -430,7 +700,13 @@ pub struct QuoteSend<'info> {
         bump = oft_config.bump
     )]
     pub oft_config: Account<'info, OftConfig>,
-    
+
+    #[account(
+        seeds = [b"dvn_set"],
+        bump = dvn_set.bump
+    )]
+    pub dvn_set: Account<'info, DvnSet>,
+
     /// CHECK: LayerZero endpoint for fee quote
     #[account(address = oft_config.endpoint_program)]
     pub endpoint_program: AccountInfo<'info>,
@@ -478,7 +754,51 @@ pub struct TransferAdmin<'info> {
         has_one = admin
     )]
     pub oft_config: Account<'info, OftConfig>,
-    
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetDvnSet<'info> {
+    #[account(
+        seeds = [b"oft_config"],
+        bump = oft_config.bump,
+        has_one = admin
+    )]
+    pub oft_config: Account<'info, OftConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + DvnSet::INIT_SPACE,
+        seeds = [b"dvn_set"],
+        bump
+    )]
+    pub dvn_set: Account<'info, DvnSet>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDvnThreshold<'info> {
+    #[account(
+        seeds = [b"oft_config"],
+        bump = oft_config.bump,
+        has_one = admin
+    )]
+    pub oft_config: Account<'info, OftConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"dvn_set"],
+        bump = dvn_set.bump,
+        has_one = admin
+    )]
+    pub dvn_set: Account<'info, DvnSet>,
+
     pub admin: Signer<'info>,
 }
 
@@ -495,6 +815,16 @@ pub struct OftConfig {
     pub paused: bool,
     pub total_bridged_in: u64,
     pub total_bridged_out: u64,
+    /// This mint's own decimals, e.g. 9. Must be >= `SHARED_DECIMALS`.
+    pub local_decimals: u8,
+    /// `10^(local_decimals - SHARED_DECIMALS)`. Wire amounts are in shared
+    /// decimals; multiply by this to get local amounts and divide to get
+    /// shared amounts.
+    pub decimal_conversion_rate: u64,
+    /// Endpoint-owned account `send` transfers native fees into.
+    pub fee_collector: Pubkey,
+    /// Cumulative native fees collected across all `send` calls.
+    pub total_fees_collected: u64,
     pub bump: u8,
 }
 
@@ -504,6 +834,47 @@ pub struct PeerConfig {
     pub eid: u32,
     pub address: [u8; 32],
     pub enabled: bool,
+    /// Highest `origin.nonce` accepted from this peer so far, so a stale or
+    /// replayed delivery older than one already processed is rejected even
+    /// before the `claim` PDA's `init` would catch an exact duplicate.
+    pub max_received_nonce: u64,
+    pub bump: u8,
+}
+
+/// Marker account for a single processed inbound message, seeded by
+/// `(origin.src_eid, origin.nonce)`. Its existence is the replay guard:
+/// `init`-ing it inside `LzReceive` fails with an account-already-in-use
+/// error if the same `(src_eid, nonce)` is ever delivered again.
+#[account]
+#[derive(InitSpace)]
+pub struct Claim {
+    pub bump: u8,
+}
+
+/// An ordered set of secp256k1 DVN verifier addresses (20-byte Ethereum-style
+/// keys, derived the same way as a guardian key: `keccak256(pubkey)[12..32]`)
+/// and the quorum threshold `lz_receive` requires signatures against.
+#[account]
+#[derive(InitSpace)]
+pub struct DvnSet {
+    pub admin: Pubkey,
+    pub threshold: u8,
+    #[max_len(MAX_DVNS)]
+    pub verifiers: Vec<[u8; 20]>,
+    pub bump: u8,
+}
+
+/// Records where this mint's canonical supply actually lives, following the
+/// wrapped-asset-meta pattern from the Wormhole token bridge. Populated once
+/// via `register_origin`; a natively-Solana OFT registers `SOLANA_EID` and
+/// its own mint as the "origin" rather than leaving this unset.
+#[account]
+#[derive(InitSpace)]
+pub struct WrappedMeta {
+    pub mint: Pubkey,
+    pub origin_eid: u32,
+    pub origin_token_address: [u8; 32],
+    pub origin_decimals: u8,
     pub bump: u8,
 }
 
@@ -520,6 +891,9 @@ pub struct SendParam {
     pub extra_options: Vec<u8>,
     pub compose_msg: Vec<u8>,
     pub oft_cmd: Vec<u8>,
+    /// Maximum native fee (lamports) the caller is willing to pay; `send`
+    /// rejects with `FeeTooLow` if the quote (live or fallback) exceeds this.
+    pub max_fee: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -529,6 +903,13 @@ pub struct Origin {
     pub nonce: u64,
 }
 
+/// One DVN's attestation over a [`DvnSet`] message digest: `r‖s‖recovery_id`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DvnSignature {
+    pub dvn_index: u8,
+    pub signature: [u8; 65],
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct MessagingFee {
     pub native_fee: u64,
@@ -554,9 +935,23 @@ pub struct SendEvent {
     pub to: [u8; 32],
     pub amount_ld: u64,
     pub sender: Pubkey,
+    /// This mint's canonical origin EID, from `WrappedMeta`. Equal to
+    /// `SOLANA_EID` for a natively-Solana OFT.
+    pub origin_eid: u32,
     pub timestamp: i64,
 }
 
+/// Emitted from `send` whenever flooring `amount_ld` to the shared-decimal
+/// conversion rate leaves a remainder, so clients can reconcile the exact
+/// burned amount against what the caller originally requested.
+#[event]
+pub struct DustRemoved {
+    pub sender: Pubkey,
+    pub amount_ld: u64,
+    pub clean_amount_ld: u64,
+    pub dust: u64,
+}
+
 #[event]
 pub struct ReceiveEvent {
     pub guid: [u8; 32],
@@ -565,6 +960,10 @@ pub struct ReceiveEvent {
     pub to: Pubkey,
     pub amount_ld: u64,
     pub nonce: u64,
+    pub sender: Option<Pubkey>,
+    /// This mint's canonical origin EID, from `WrappedMeta`. Equal to
+    /// `SOLANA_EID` for a natively-Solana OFT.
+    pub origin_eid: u32,
     pub timestamp: i64,
 }
 
@@ -572,27 +971,229 @@ pub struct ReceiveEvent {
 // Helper Functions
 // ============================================================================
 
-fn encode_oft_message(msg_type: u8, to: [u8; 32], amount: u64) -> Vec<u8> {
+/// An OFT message decoded off the wire. `sender`/`compose_msg` are only
+/// populated for `MSG_TYPE_SEND_AND_CALL` messages.
+struct DecodedOftMessage {
+    msg_type: u8,
+    to: [u8; 32],
+    amount_ld: u64,
+    sender: Option<Pubkey>,
+    compose_msg: Vec<u8>,
+}
+
+/// Encodes the `[msg_type(1) | to(32) | amount(8)]` plain-transfer header,
+/// appending `[sender(32) | compose_msg]` when `compose` is `Some` so the
+/// remote side can CPI into the recipient program after minting.
+fn encode_oft_message(msg_type: u8, to: [u8; 32], amount: u64, compose: Option<(Pubkey, &[u8])>) -> Vec<u8> {
     let mut message = Vec::with_capacity(41);
     message.push(msg_type);
     message.extend_from_slice(&to);
     message.extend_from_slice(&amount.to_be_bytes());
+    if let Some((sender, compose_msg)) = compose {
+        message.extend_from_slice(sender.as_ref());
+        message.extend_from_slice(compose_msg);
+    }
     message
 }
 
-fn decode_oft_message(message: &[u8]) -> Result<(u8, [u8; 32], u64)> {
+fn decode_oft_message(message: &[u8]) -> Result<DecodedOftMessage> {
     require!(message.len() >= 41, OftError::InvalidMessage);
-    
+
     let msg_type = message[0];
-    
+
     let mut to = [0u8; 32];
     to.copy_from_slice(&message[1..33]);
-    
+
     let mut amount_bytes = [0u8; 8];
     amount_bytes.copy_from_slice(&message[33..41]);
-    let amount = u64::from_be_bytes(amount_bytes);
-    
-    Ok((msg_type, to, amount))
+    let amount_ld = u64::from_be_bytes(amount_bytes);
+
+    if msg_type == MSG_TYPE_SEND_AND_CALL {
+        require!(message.len() >= 73, OftError::InvalidMessage);
+        let sender = Pubkey::new_from_array(message[41..73].try_into().unwrap());
+        let compose_msg = message[73..].to_vec();
+        Ok(DecodedOftMessage {
+            msg_type,
+            to,
+            amount_ld,
+            sender: Some(sender),
+            compose_msg,
+        })
+    } else {
+        Ok(DecodedOftMessage {
+            msg_type,
+            to,
+            amount_ld,
+            sender: None,
+            compose_msg: Vec::new(),
+        })
+    }
+}
+
+/// CPIs into the compose target program with an Anchor-style
+/// `sha256("global:lz_compose")[0..8]` discriminator, followed by the
+/// borsh-serialized `(src_eid, sender, guid, amount_ld, compose_msg)` tuple.
+/// `compose_program` is the CPI target and `compose_accounts` are forwarded
+/// to it as-is; `lz_receive` takes both from its `remaining_accounts`.
+fn invoke_compose(
+    compose_program: &AccountInfo,
+    compose_accounts: &[AccountInfo],
+    src_eid: u32,
+    sender: Pubkey,
+    guid: [u8; 32],
+    amount_ld: u64,
+    compose_msg: &[u8],
+) -> Result<()> {
+    use anchor_lang::solana_program::hash::hash;
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+    use anchor_lang::solana_program::program::invoke;
+
+    let discriminator = hash(b"global:lz_compose").to_bytes();
+    let mut data = discriminator[0..8].to_vec();
+    data.extend_from_slice(&src_eid.try_to_vec().map_err(|_| OftError::InvalidMessage)?);
+    data.extend_from_slice(&sender.try_to_vec().map_err(|_| OftError::InvalidMessage)?);
+    data.extend_from_slice(&guid.try_to_vec().map_err(|_| OftError::InvalidMessage)?);
+    data.extend_from_slice(&amount_ld.try_to_vec().map_err(|_| OftError::InvalidMessage)?);
+    data.extend_from_slice(&compose_msg.to_vec().try_to_vec().map_err(|_| OftError::InvalidMessage)?);
+
+    let account_metas = compose_accounts
+        .iter()
+        .map(|acc| {
+            if acc.is_writable {
+                AccountMeta::new(*acc.key, acc.is_signer)
+            } else {
+                AccountMeta::new_readonly(*acc.key, acc.is_signer)
+            }
+        })
+        .collect();
+
+    let ix = Instruction {
+        program_id: *compose_program.key,
+        accounts: account_metas,
+        data,
+    };
+
+    let mut account_infos = vec![compose_program.clone()];
+    account_infos.extend_from_slice(compose_accounts);
+
+    invoke(&ix, &account_infos)?;
+    Ok(())
+}
+
+/// The digest a [`DvnSet`] signs over: `keccak(src_eid || sender || nonce || keccak(message))`.
+/// Binding the message's own hash (rather than the raw bytes) keeps the
+/// digest a fixed 32 bytes regardless of message length.
+fn compute_dvn_digest(src_eid: u32, sender: &[u8; 32], nonce: u64, message: &[u8]) -> [u8; 32] {
+    let message_hash = keccak::hash(message).to_bytes();
+
+    let mut data = Vec::with_capacity(4 + 32 + 8 + 32);
+    data.extend_from_slice(&src_eid.to_le_bytes());
+    data.extend_from_slice(sender);
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&message_hash);
+
+    keccak::hash(&data).to_bytes()
+}
+
+/// Verifies that at least `dvn_set.threshold` signatures recover to distinct
+/// registered DVN addresses at strictly increasing indices.
+fn verify_dvn_quorum(
+    dvn_set: &DvnSet,
+    message_hash: &[u8; 32],
+    signatures: &[DvnSignature],
+) -> Result<()> {
+    require!(signatures.len() >= dvn_set.threshold as usize, OftError::QuorumNotMet);
+
+    let mut last_index: Option<u8> = None;
+    for sig in signatures.iter() {
+        if let Some(last) = last_index {
+            require!(sig.dvn_index > last, OftError::SignaturesOutOfOrder);
+        }
+        last_index = Some(sig.dvn_index);
+
+        let dvn_key = dvn_set
+            .verifiers
+            .get(sig.dvn_index as usize)
+            .ok_or(OftError::InvalidDvnIndex)?;
+
+        let recovery_id = sig.signature[64];
+        let recovered_pubkey = secp256k1_recover(message_hash, recovery_id, &sig.signature[..64])
+            .map_err(|_| OftError::InvalidDvnSignature)?;
+        let recovered_address = keccak::hash(&recovered_pubkey.to_bytes()).to_bytes();
+
+        require!(&recovered_address[12..32] == dvn_key, OftError::InvalidDvnSignature);
+    }
+
+    Ok(())
+}
+
+/// Wire size of an OFT message for the given compose payload: the fixed
+/// 41-byte `[msg_type | to | amount]` header, plus `[sender | compose_msg]`
+/// when `compose_msg` is non-empty.
+fn estimate_message_size(compose_msg: &[u8]) -> u32 {
+    let mut size = 1 + 32 + 8;
+    if !compose_msg.is_empty() {
+        size += 32 + compose_msg.len();
+    }
+    size as u32
+}
+
+/// Attempts to obtain a live fee quote by CPI-ing into the LayerZero
+/// endpoint program, using the same runtime-computed Anchor discriminator
+/// convention as [`invoke_compose`]. Returns `None` if the call fails or the
+/// program doesn't report a quote via return data (e.g. a stub endpoint
+/// program in local testing), in which case the caller should fall back to
+/// [`fallback_quote`].
+fn quote_via_endpoint(
+    endpoint_program: &AccountInfo,
+    dst_eid: u32,
+    message_size: u32,
+    dvn_count: u8,
+) -> Option<MessagingFee> {
+    use anchor_lang::solana_program::hash::hash;
+    use anchor_lang::solana_program::instruction::Instruction;
+    use anchor_lang::solana_program::program::{get_return_data, invoke};
+
+    let discriminator = hash(b"global:quote").to_bytes();
+    let mut data = discriminator[0..8].to_vec();
+    data.extend_from_slice(&dst_eid.to_le_bytes());
+    data.extend_from_slice(&message_size.to_le_bytes());
+    data.push(dvn_count);
+
+    let ix = Instruction {
+        program_id: *endpoint_program.key,
+        accounts: Vec::new(),
+        data,
+    };
+
+    invoke(&ix, &[endpoint_program.clone()]).ok()?;
+
+    let (program_id, return_data) = get_return_data()?;
+    if program_id != *endpoint_program.key || return_data.len() < 16 {
+        return None;
+    }
+
+    let native_fee = u64::from_le_bytes(return_data[0..8].try_into().ok()?);
+    let lz_token_fee = u64::from_le_bytes(return_data[8..16].try_into().ok()?);
+
+    Some(MessagingFee { native_fee, lz_token_fee })
+}
+
+/// Static byte-based fee estimate, used when [`quote_via_endpoint`] can't
+/// reach a live endpoint quote.
+///
+/// Estimated fees for Solana -> Ethereum:
+/// - DVN verification: ~0.0005 SOL per DVN (need 2+ DVNs)
+/// - Executor gas on Ethereum: ~0.001 SOL equivalent
+/// Total: ~0.002-0.005 SOL
+fn fallback_quote(message_size: u32) -> MessagingFee {
+    let base_fee = 2_000_000u64; // 0.002 SOL in lamports
+    let per_byte_fee = 100u64; // Small fee per byte
+
+    MessagingFee {
+        native_fee: base_fee + (message_size as u64 * per_byte_fee),
+        lz_token_fee: 0, // Not using LZ token payment
+    }
 }
 
 fn generate_guid(
@@ -601,8 +1202,6 @@ fn generate_guid(
     amount: u64,
     timestamp: u64,
 ) -> [u8; 32] {
-    use anchor_lang::solana_program::keccak;
-    
     let mut data = Vec::new();
     data.extend_from_slice(sender.as_ref());
     data.extend_from_slice(&dst_eid.to_le_bytes());
@@ -641,7 +1240,37 @@ pub enum OftError {
     
     #[msg("Arithmetic overflow")]
     Overflow,
-    
+
     #[msg("Unauthorized operation")]
     Unauthorized,
+
+    #[msg("Message nonce is not greater than the last one processed from this peer")]
+    NonceRegressed,
+
+    #[msg("SEND_AND_CALL message requires a compose target program in remaining_accounts")]
+    MissingComposeProgram,
+
+    #[msg("Fewer than the required threshold of valid, distinct DVN signatures")]
+    QuorumNotMet,
+
+    #[msg("DVN signatures must be strictly ordered by increasing dvn_index")]
+    SignaturesOutOfOrder,
+
+    #[msg("DVN signature references an index outside the registered DVN set")]
+    InvalidDvnIndex,
+
+    #[msg("DVN signature does not recover to its claimed verifier address")]
+    InvalidDvnSignature,
+
+    #[msg("Invalid DVN set configuration")]
+    InvalidDvnSet,
+
+    #[msg("Local decimals must be greater than or equal to SHARED_DECIMALS")]
+    InvalidLocalDecimals,
+
+    #[msg("Quoted messaging fee exceeds the caller's max_fee")]
+    FeeTooLow,
+
+    #[msg("fee_collector account does not match oft_config.fee_collector")]
+    FeeCollectorMismatch,
 }
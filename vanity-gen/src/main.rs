@@ -1,56 +1,363 @@
+mod fast;
+
 use clap::Parser;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tiny_keccak::{Hasher, Keccak};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about = "CREATE2 Vanity Address Generator", long_about = None)]
 struct Args {
-    /// Init code hash (with or without 0x prefix)
+    /// Address-derivation scheme to mine for
+    #[arg(long, value_enum, default_value = "create2")]
+    scheme: Scheme,
+
+    /// Init code hash (with or without 0x prefix). Required for --scheme
+    /// create2; irrelevant for create/create3 (a CREATE3-deployed
+    /// contract's address doesn't depend on its init code).
     #[arg(short, long)]
-    init_hash: String,
+    init_hash: Option<String>,
 
-    /// CREATE2 factory address (with or without 0x prefix)
+    /// Deployer/factory address (with or without 0x prefix): the CREATE2
+    /// factory for create2/create3, or the deploying EOA/contract for create
     #[arg(short, long)]
     factory: String,
 
-    /// Address prefix (without 0x)
-    #[arg(short, long)]
+    /// Address prefix (without 0x). Combined with --target as one more
+    /// search target unless left empty.
+    #[arg(short, long, default_value = "")]
     prefix: String,
 
-    /// Address suffix
-    #[arg(short, long)]
+    /// Address suffix. Combined with --target as one more search target
+    /// unless left empty.
+    #[arg(short, long, default_value = "")]
     suffix: String,
 
+    /// An additional (prefix, suffix) target, as "PREFIX:SUFFIX" (either
+    /// half may be empty). Repeatable; the search stops at the first
+    /// address matching any target, --prefix/--suffix included.
+    #[arg(long = "target")]
+    targets: Vec<String>,
+
+    /// Match the EIP-55 mixed-case checksum representation: cased letters in
+    /// prefixes/suffixes must match case as well as value, not just hex value
+    #[arg(short, long)]
+    checksum: bool,
+
     /// Number of threads (default: number of CPU cores)
     #[arg(short, long)]
     threads: Option<usize>,
+
+    /// Persist search progress to this path every 30s and resume from it on
+    /// startup if it matches the current factory/init-hash/pattern. Only the
+    /// pattern search reads this; --zero-bytes runs are not checkpointed
+    #[arg(long)]
+    state: Option<String>,
+
+    /// Switch to scoring mode: instead of matching --prefix/--suffix, search
+    /// for the address with the most zero bytes (leading zero bytes weighted
+    /// more heavily), stopping once this target score is reached
+    #[arg(long)]
+    zero_bytes: Option<u64>,
+
+    /// In --zero-bytes mode, stop and report the best address found after
+    /// this many attempts
+    #[arg(long)]
+    max_attempts: Option<u64>,
+
+    /// In --zero-bytes mode, stop and report the best address found after
+    /// this many seconds
+    #[arg(long)]
+    duration_secs: Option<u64>,
+
+    /// Emit the result as a single JSON object on stdout (salt, address,
+    /// matched_target, attempts, elapsed_secs, hash_rate) instead of the
+    /// banner output, so the search can be scripted into a pipeline. Only the
+    /// pattern search reads this; --zero-bytes mode always prints the banner
+    #[arg(long)]
+    json: bool,
+
+    /// Also append the JSON result object to this file
+    #[arg(long)]
+    output: Option<String>,
+
+    /// For --scheme create2, recompute any match through the straightforward
+    /// (non-midstate) implementation before accepting it, to guard against
+    /// state-reuse bugs in the precomputed fast path
+    #[arg(long)]
+    verify: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Scheme {
+    /// `keccak256(rlp([deployer, nonce]))[12..]` — plain sequential deploys
+    Create,
+    /// `keccak256(0xff ++ factory ++ salt ++ keccak256(init_code))[12..]`
+    Create2,
+    /// Two-step proxy pattern where only `salt` (not the deployed contract's
+    /// init code) affects the final address
+    Create3,
+}
+
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Persisted search progress, enough to resume each thread's salt cursor and
+/// keep cumulative attempt counts (and therefore H/s and difficulty
+/// reporting) accurate across a restart. Only trusted on load if
+/// `factory`/`init_code_hash`/`prefix`/`suffix`/`checksum` still match the
+/// current invocation.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SearchState {
+    factory: String,
+    init_code_hash: String,
+    prefix: String,
+    suffix: String,
+    checksum: bool,
+    attempts: u64,
+    next_salt_per_thread: Vec<u64>,
+}
+
+/// Reads a state file written by [`save_state`], if one exists.
+fn load_state(path: &str) -> Option<SearchState> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Atomically writes `state` to `path` via a temp file + rename, so a crash
+/// mid-write never leaves a corrupt state file behind.
+fn save_state(path: &str, state: &SearchState) {
+    let tmp_path = format!("{path}.tmp");
+    if let Ok(json) = serde_json::to_string(state) {
+        if fs::write(&tmp_path, json).is_ok() {
+            let _ = fs::rename(&tmp_path, path);
+        }
+    }
+}
+
+/// Removes the state file once a search completes successfully.
+fn clear_state(path: &str) {
+    let _ = fs::remove_file(path);
 }
 
 fn strip_0x(s: &str) -> &str {
     s.strip_prefix("0x").unwrap_or(s)
 }
 
-fn calculate_create2_address(factory: &[u8; 20], salt: &[u8; 32], init_code_hash: &[u8; 32]) -> [u8; 20] {
+fn keccak256(data: &[u8]) -> [u8; 32] {
     let mut hasher = Keccak::v256();
-    hasher.update(&[0xff]);
-    hasher.update(factory);
-    hasher.update(salt);
-    hasher.update(init_code_hash);
-    
-    let mut hash = [0u8; 32];
-    hasher.finalize(&mut hash);
-    
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+fn calculate_create2_address(factory: &[u8; 20], salt: &[u8; 32], init_code_hash: &[u8; 32]) -> [u8; 20] {
+    let mut preimage = Vec::with_capacity(1 + 20 + 32 + 32);
+    preimage.push(0xff);
+    preimage.extend_from_slice(factory);
+    preimage.extend_from_slice(salt);
+    preimage.extend_from_slice(init_code_hash);
+
+    let hash = keccak256(&preimage);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..32]);
+    address
+}
+
+/// RLP-encodes a nonce per the integer encoding rules: zero is the empty
+/// string, values below 0x80 encode as that single byte, and everything
+/// else is a length-prefixed big-endian byte string with no leading zeros.
+fn rlp_encode_nonce(nonce: u64) -> Vec<u8> {
+    if nonce == 0 {
+        vec![0x80]
+    } else if nonce < 0x80 {
+        vec![nonce as u8]
+    } else {
+        let bytes = nonce.to_be_bytes();
+        let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap();
+        let trimmed = &bytes[first_nonzero..];
+        let mut out = Vec::with_capacity(1 + trimmed.len());
+        out.push(0x80 + trimmed.len() as u8);
+        out.extend_from_slice(trimmed);
+        out
+    }
+}
+
+/// Derives the plain CREATE address for `deployer` at `nonce`:
+/// `keccak256(rlp([deployer, nonce]))[12..]`. The RLP payload (a 21-byte
+/// address plus at most a 9-byte nonce) always fits the single-byte short
+/// list length prefix.
+fn calculate_create_address(deployer: &[u8; 20], nonce: u64) -> [u8; 20] {
+    let nonce_rlp = rlp_encode_nonce(nonce);
+
+    let mut payload = Vec::with_capacity(1 + 20 + nonce_rlp.len());
+    payload.push(0x94); // 0x80 + 20-byte string
+    payload.extend_from_slice(deployer);
+    payload.extend_from_slice(&nonce_rlp);
+
+    let mut rlp = Vec::with_capacity(1 + payload.len());
+    rlp.push(0xc0 + payload.len() as u8);
+    rlp.extend_from_slice(&payload);
+
+    let hash = keccak256(&rlp);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..32]);
+    address
+}
+
+/// Init-code hash of the minimal CREATE3 proxy (the de facto standard used
+/// by Solmate's `CREATE3` library and compatible deployers): a tiny
+/// contract whose only job is to `CREATE` the real contract, so the final
+/// address depends only on `salt`, never on the deployed contract's own
+/// init code.
+const CREATE3_PROXY_INIT_CODE_HASH: [u8; 32] = [
+    0x21, 0xc3, 0x5d, 0xbe, 0x1b, 0x34, 0x4a, 0x24, 0x88, 0xcf, 0x33, 0x21, 0xd6, 0xce, 0x54, 0x2f,
+    0x8e, 0x9f, 0x30, 0x55, 0x44, 0xff, 0x09, 0xe4, 0x99, 0x3a, 0x62, 0x31, 0x9a, 0x49, 0x7c, 0x1f,
+];
+
+/// Derives the final CREATE3 address for `salt`: first the proxy's own
+/// CREATE2 address, then the address that proxy's first (nonce-1) CREATE
+/// deploy would have, `keccak256(0xd6 ++ 0x94 ++ proxy ++ 0x01)[12..]`.
+fn calculate_create3_address(factory: &[u8; 20], salt: &[u8; 32]) -> [u8; 20] {
+    let proxy = calculate_create2_address(factory, salt, &CREATE3_PROXY_INIT_CODE_HASH);
+
+    let mut preimage = [0u8; 23];
+    preimage[0] = 0xd6;
+    preimage[1] = 0x94;
+    preimage[2..22].copy_from_slice(&proxy);
+    preimage[22] = 0x01;
+
+    let hash = keccak256(&preimage);
     let mut address = [0u8; 20];
     address.copy_from_slice(&hash[12..32]);
     address
 }
 
-fn matches_pattern(address: &[u8; 20], prefix: &str, suffix: &str) -> bool {
-    let addr_hex = hex::encode(address);
-    addr_hex.starts_with(&prefix.to_lowercase()) && addr_hex.ends_with(&suffix.to_lowercase())
+/// Derives an address under the configured scheme. `nonce` is the per-thread
+/// counter reinterpreted as a CREATE nonce; `salt` is that same counter
+/// encoded into the low 8 bytes of a 32-byte CREATE2/CREATE3 salt.
+fn calculate_address(
+    scheme: Scheme,
+    factory: &[u8; 20],
+    nonce: u64,
+    salt: &[u8; 32],
+    init_code_hash: Option<&[u8; 32]>,
+) -> [u8; 20] {
+    match scheme {
+        Scheme::Create => calculate_create_address(factory, nonce),
+        Scheme::Create2 => calculate_create2_address(
+            factory,
+            salt,
+            init_code_hash.expect("--init-hash is required for --scheme create2"),
+        ),
+        Scheme::Create3 => calculate_create3_address(factory, salt),
+    }
+}
+
+/// Renders `address` per EIP-55: lowercase 40-nibble hex, hashed as an ASCII
+/// string with Keccak-256, then each letter nibble is uppercased when the
+/// corresponding nibble of the hash is >= 8.
+fn to_checksum_address(address: &[u8; 20]) -> String {
+    let lower = hex::encode(address);
+    let hash = keccak256(lower.as_bytes());
+
+    lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if !c.is_ascii_alphabetic() {
+                return c;
+            }
+            let hash_nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+            if hash_nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn matches_pattern(address: &[u8; 20], prefix: &str, suffix: &str, checksum: bool) -> bool {
+    if checksum {
+        let addr_checksummed = to_checksum_address(address);
+        addr_checksummed.starts_with(prefix) && addr_checksummed.ends_with(suffix)
+    } else {
+        let addr_hex = hex::encode(address);
+        addr_hex.starts_with(&prefix.to_lowercase()) && addr_hex.ends_with(&suffix.to_lowercase())
+    }
+}
+
+/// Parses a "PREFIX:SUFFIX" `--target` value into its two halves.
+fn parse_target(spec: &str) -> (String, String) {
+    match spec.split_once(':') {
+        Some((prefix, suffix)) => (prefix.to_string(), suffix.to_string()),
+        None => (spec.to_string(), String::new()),
+    }
+}
+
+/// Builds the full list of (prefix, suffix) targets to search for: any
+/// `--target PREFIX:SUFFIX` entries, plus `--prefix`/`--suffix` as one more
+/// target unless both are empty and at least one `--target` was given.
+fn collect_targets(args: &Args) -> Vec<(String, String)> {
+    let mut targets: Vec<(String, String)> = args.targets.iter().map(|t| parse_target(t)).collect();
+    if !args.prefix.is_empty() || !args.suffix.is_empty() || targets.is_empty() {
+        targets.push((args.prefix.clone(), args.suffix.clone()));
+    }
+    targets
+}
+
+/// Checks `address` against every target, returning the index of the first
+/// one it matches, if any.
+fn matches_any_target(address: &[u8; 20], targets: &[(String, String)], checksum: bool) -> Option<usize> {
+    targets
+        .iter()
+        .position(|(prefix, suffix)| matches_pattern(address, prefix, suffix, checksum))
+}
+
+/// Machine-readable search result for `--json`.
+#[derive(Serialize)]
+struct JsonResult {
+    salt: String,
+    address: String,
+    matched_target: String,
+    attempts: u64,
+    elapsed_secs: f64,
+    hash_rate: f64,
+}
+
+/// Prints `result` as JSON to stdout, and appends it to `output_path` if
+/// one was given.
+fn emit_json_result(result: &JsonResult, output_path: Option<&str>) {
+    let json = serde_json::to_string_pretty(result).expect("serialize result");
+    println!("{json}");
+    if let Some(path) = output_path {
+        use std::io::Write;
+        if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(path) {
+            let _ = writeln!(file, "{json}");
+        }
+    }
+}
+
+/// Scores an address for the `--zero-bytes` optimizer: one point per zero
+/// byte, plus an extra point for each zero byte in the unbroken leading run,
+/// since leading zero bytes compress best in calldata and dominate the gas
+/// savings of a "vanity" deployer address.
+fn score_zero_bytes(address: &[u8; 20]) -> u64 {
+    let mut score = 0u64;
+    let mut leading = true;
+    for &b in address.iter() {
+        if b == 0 {
+            score += if leading { 2 } else { 1 };
+        } else {
+            leading = false;
+        }
+    }
+    score
 }
 
 fn num_cpus() -> usize {
@@ -69,40 +376,112 @@ fn main() {
 
     // Parse inputs
     let factory_hex = strip_0x(&args.factory);
-    let init_hash_hex = strip_0x(&args.init_hash);
-    
+
     let factory: [u8; 20] = hex::decode(factory_hex)
         .expect("Invalid factory address")
         .try_into()
         .expect("Factory must be 20 bytes");
-    
-    let init_code_hash: [u8; 32] = hex::decode(init_hash_hex)
-        .expect("Invalid init code hash")
-        .try_into()
-        .expect("Init code hash must be 32 bytes");
+
+    let init_hash_hex = args.init_hash.as_deref().map(strip_0x).unwrap_or("").to_string();
+    let init_code_hash: Option<[u8; 32]> = args.init_hash.as_deref().map(|h| {
+        hex::decode(strip_0x(h))
+            .expect("Invalid init code hash")
+            .try_into()
+            .expect("Init code hash must be 32 bytes")
+    });
+    if args.scheme == Scheme::Create2 && init_code_hash.is_none() {
+        panic!("--init-hash is required for --scheme create2");
+    }
 
     println!("Configuration:");
+    println!("  Scheme:      {:?}", args.scheme);
     println!("  Factory:     0x{}", hex::encode(factory));
-    println!("  Init Hash:   0x{}", hex::encode(init_code_hash));
-    println!("  Prefix:      {}", args.prefix);
-    println!("  Suffix:      {}", args.suffix);
+    if let Some(hash) = &init_code_hash {
+        println!("  Init Hash:   0x{}", hex::encode(hash));
+    }
     println!("  Threads:     {}", threads);
-    
-    // Estimate difficulty
-    let prefix_bits = args.prefix.len() * 4;
-    let suffix_bits = args.suffix.len() * 4;
-    let total_bits = prefix_bits + suffix_bits;
+
+    match args.zero_bytes {
+        Some(target_score) => run_score_search(&args, &factory, init_code_hash.as_ref(), threads, target_score),
+        None => run_pattern_search(&args, &factory, init_code_hash.as_ref(), threads, factory_hex, &init_hash_hex),
+    }
+}
+
+fn run_pattern_search(
+    args: &Args,
+    factory: &[u8; 20],
+    init_code_hash: Option<&[u8; 32]>,
+    threads: usize,
+    factory_hex: &str,
+    init_hash_hex: &str,
+) {
+    let targets = collect_targets(args);
+    for (prefix, suffix) in &targets {
+        println!(
+            "  Target:      {}...{}{}",
+            prefix, suffix, if args.checksum { " (checksum)" } else { "" }
+        );
+    }
+
+    // Estimate difficulty of the easiest target. In checksum mode, each
+    // cased letter (a-f/A-F) contributes an extra bit on top of its 4 bits
+    // of hex value, since the EIP-55 case is an independent ~50/50 coin
+    // flip per nibble. Multiple targets only make the overall search
+    // easier, so report the lowest per-target bit count rather than
+    // summing across targets.
+    let pattern_bits = |pattern: &str| -> usize {
+        pattern
+            .chars()
+            .map(|c| if args.checksum && c.is_ascii_alphabetic() { 5 } else { 4 })
+            .sum()
+    };
+    let total_bits = targets
+        .iter()
+        .map(|(prefix, suffix)| pattern_bits(prefix) + pattern_bits(suffix))
+        .min()
+        .unwrap_or(0);
     let expected_attempts = 1u64 << total_bits;
-    
+
     println!("\nâš™ï¸  Difficulty:");
-    println!("  Prefix bits: {} ({} chars)", prefix_bits, args.prefix.len());
-    println!("  Suffix bits: {} ({} chars)", suffix_bits, args.suffix.len());
-    println!("  Total bits:  {}", total_bits);
+    println!("  Targets:     {}", targets.len());
+    println!("  Easiest bits:{}", total_bits);
     println!("  Expected:    ~{} attempts", format_number(expected_attempts));
     println!("\nğŸ” Searching...\n");
 
+    // Resume from a previous run's state file if one exists and matches
+    // this invocation's factory/init-hash/pattern. Checkpointing is keyed
+    // on the legacy single --prefix/--suffix target only; runs using
+    // --target don't resume (the saved cursor would need to track every
+    // target, and multi-target runs are typically short enough not to need it).
+    let resumed = args.state.as_deref().and_then(load_state).filter(|state| {
+        state.factory == factory_hex
+            && state.init_code_hash == init_hash_hex
+            && state.prefix == args.prefix
+            && state.suffix == args.suffix
+            && state.checksum == args.checksum
+            && state.next_salt_per_thread.len() == threads
+            && args.targets.is_empty()
+    });
+
+    if resumed.is_some() {
+        println!("  Resuming from saved state at {}\n", args.state.as_deref().unwrap_or(""));
+    }
+
+    let initial_attempts = resumed.as_ref().map(|s| s.attempts).unwrap_or(0);
+    let cursors: Arc<Vec<AtomicU64>> = Arc::new(
+        (0..threads)
+            .map(|thread_id| {
+                let start = resumed
+                    .as_ref()
+                    .map(|s| s.next_salt_per_thread[thread_id])
+                    .unwrap_or(thread_id as u64 * 1_000_000);
+                AtomicU64::new(start)
+            })
+            .collect(),
+    );
+
     let found = Arc::new(AtomicBool::new(false));
-    let attempts = Arc::new(AtomicU64::new(0));
+    let attempts = Arc::new(AtomicU64::new(initial_attempts));
     let start_time = Instant::now();
 
     // Spawn progress reporter
@@ -117,16 +496,55 @@ fn main() {
             let count = attempts_clone.load(Ordering::Relaxed);
             let elapsed = start_time.elapsed().as_secs_f64();
             let rate = count as f64 / elapsed;
-            println!("  {:>12} attempts | {:.1}s | {:.0} H/s", 
+            println!("  {:>12} attempts | {:.1}s | {:.0} H/s",
                 format_number(count), elapsed, rate);
         }
     });
 
+    // Spawn periodic state checkpointer, if requested.
+    if let Some(path) = args.state.clone() {
+        let found = Arc::clone(&found);
+        let attempts = Arc::clone(&attempts);
+        let cursors = Arc::clone(&cursors);
+        let factory_hex = factory_hex.to_string();
+        let init_hash_hex = init_hash_hex.to_string();
+        let prefix = args.prefix.clone();
+        let suffix = args.suffix.clone();
+        let checksum = args.checksum;
+        std::thread::spawn(move || loop {
+            std::thread::sleep(CHECKPOINT_INTERVAL);
+            if found.load(Ordering::Relaxed) {
+                break;
+            }
+            save_state(
+                &path,
+                &SearchState {
+                    factory: factory_hex.clone(),
+                    init_code_hash: init_hash_hex.clone(),
+                    prefix: prefix.clone(),
+                    suffix: suffix.clone(),
+                    checksum,
+                    attempts: attempts.load(Ordering::Relaxed),
+                    next_salt_per_thread: cursors.iter().map(|c| c.load(Ordering::Relaxed)).collect(),
+                },
+            );
+        });
+    }
+
+    // For --scheme create2, the fast path precomputes the Keccak sponge
+    // state for the constant `0xff ++ factory ++ init_code_hash` bytes once
+    // here, so each candidate only patches in its salt and runs a single
+    // permutation instead of re-absorbing the whole 85-byte preimage.
+    let midstate = match (args.scheme, init_code_hash) {
+        (Scheme::Create2, Some(hash)) => Some(fast::Create2Midstate::new(factory, hash)),
+        _ => None,
+    };
+
     // Parallel search
     let result = (0..threads).into_par_iter().find_map_any(|thread_id| {
-        let mut salt_value = thread_id as u64 * 1_000_000;
+        let mut salt_value = cursors[thread_id].load(Ordering::Relaxed);
         let mut local_attempts = 0u64;
-        
+
         loop {
             if found.load(Ordering::Relaxed) {
                 return None;
@@ -136,22 +554,34 @@ fn main() {
             let mut salt = [0u8; 32];
             salt[24..32].copy_from_slice(&salt_value.to_be_bytes());
 
-            // Calculate address
-            let address = calculate_create2_address(&factory, &salt, &init_code_hash);
+            // Calculate address, via the fast midstate path when available
+            let address = match &midstate {
+                Some(midstate) => midstate.address(&salt),
+                None => calculate_address(args.scheme, factory, salt_value, &salt, init_code_hash),
+            };
 
-            // Check match
-            if matches_pattern(&address, &args.prefix, &args.suffix) {
+            // Check match against every target
+            if let Some(target_index) = matches_any_target(&address, &targets, args.checksum) {
+                if args.verify {
+                    let reference = calculate_address(args.scheme, factory, salt_value, &salt, init_code_hash);
+                    assert_eq!(
+                        address, reference,
+                        "fast-path address mismatch for salt 0x{} — state-reuse bug",
+                        hex::encode(salt)
+                    );
+                }
                 found.store(true, Ordering::Relaxed);
                 let total_attempts = attempts.fetch_add(local_attempts, Ordering::Relaxed) + local_attempts;
-                return Some((salt, address, total_attempts));
+                return Some((salt, address, total_attempts, target_index));
             }
 
             salt_value += 1;
             local_attempts += 1;
 
-            // Update global counter periodically
+            // Update global counter and this thread's resumable cursor periodically
             if local_attempts % 10000 == 0 {
                 attempts.fetch_add(10000, Ordering::Relaxed);
+                cursors[thread_id].store(salt_value, Ordering::Relaxed);
                 local_attempts = 0;
             }
         }
@@ -159,21 +589,159 @@ fn main() {
 
     let elapsed = start_time.elapsed();
 
-    if let Some((salt, address, total_attempts)) = result {
-        println!("\nâ”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”");
-        println!("âœ… FOUND MATCHING SALT!");
-        println!("â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”");
-        println!("Salt:      0x{}", hex::encode(salt));
-        println!("Address:   0x{}", hex::encode(address));
-        println!("Attempts:  {}", format_number(total_attempts));
-        println!("Time:      {:.2}s", elapsed.as_secs_f64());
-        println!("Rate:      {:.0} H/s", total_attempts as f64 / elapsed.as_secs_f64());
-        println!("â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”\n");
+    if let Some((salt, address, total_attempts, target_index)) = result {
+        if let Some(path) = &args.state {
+            clear_state(path);
+        }
+
+        let (prefix, suffix) = &targets[target_index];
+        let hash_rate = total_attempts as f64 / elapsed.as_secs_f64();
+
+        if args.json {
+            emit_json_result(
+                &JsonResult {
+                    salt: format!("0x{}", hex::encode(salt)),
+                    address: format!("0x{}", to_checksum_address(&address)),
+                    matched_target: format!("{prefix}...{suffix}"),
+                    attempts: total_attempts,
+                    elapsed_secs: elapsed.as_secs_f64(),
+                    hash_rate,
+                },
+                args.output.as_deref(),
+            );
+        } else {
+            println!("\nâ”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”");
+            println!("âœ… FOUND MATCHING SALT!");
+            println!("â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”");
+            if args.scheme == Scheme::Create {
+                println!("Nonce:     {}", u64::from_be_bytes(salt[24..32].try_into().unwrap()));
+            } else {
+                println!("Salt:      0x{}", hex::encode(salt));
+            }
+            println!("Address:   0x{}", to_checksum_address(&address));
+            println!("Target:    {prefix}...{suffix}");
+            println!("Attempts:  {}", format_number(total_attempts));
+            println!("Time:      {:.2}s", elapsed.as_secs_f64());
+            println!("Rate:      {:.0} H/s", hash_rate);
+            println!("â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”\n");
+        }
     } else {
         println!("\nâŒ Search was interrupted or failed\n");
     }
 }
 
+/// Runs the `--zero-bytes` optimizer: instead of stopping at the first
+/// pattern match, threads continuously race to beat a shared best score,
+/// stopping once `target_score` is reached or a `--max-attempts`/
+/// `--duration-secs` budget expires.
+fn run_score_search(
+    args: &Args,
+    factory: &[u8; 20],
+    init_code_hash: Option<&[u8; 32]>,
+    threads: usize,
+    target_score: u64,
+) {
+    println!("  Zero bytes:  target score {}", target_score);
+    if let Some(max_attempts) = args.max_attempts {
+        println!("  Max attempts: {}", format_number(max_attempts));
+    }
+    if let Some(duration_secs) = args.duration_secs {
+        println!("  Duration:    {}s", duration_secs);
+    }
+    println!("\nğŸ” Searching...\n");
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let best_score = Arc::new(AtomicU64::new(0));
+    let best: Arc<Mutex<Option<([u8; 32], [u8; 20])>>> = Arc::new(Mutex::new(None));
+    let start_time = Instant::now();
+
+    // Spawn progress reporter
+    let attempts_clone = Arc::clone(&attempts);
+    let best_score_clone = Arc::clone(&best_score);
+    let found_clone = Arc::clone(&found);
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(2));
+        if found_clone.load(Ordering::Relaxed) {
+            break;
+        }
+        let count = attempts_clone.load(Ordering::Relaxed);
+        let elapsed = start_time.elapsed().as_secs_f64();
+        let rate = count as f64 / elapsed;
+        println!(
+            "  {:>12} attempts | {:.1}s | {:.0} H/s | best score {}",
+            format_number(count), elapsed, rate, best_score_clone.load(Ordering::Relaxed)
+        );
+    });
+
+    let deadline = args.duration_secs.map(|secs| start_time + Duration::from_secs(secs));
+
+    (0..threads).into_par_iter().for_each(|thread_id| {
+        let mut salt_value = thread_id as u64 * 1_000_000;
+        let mut local_attempts = 0u64;
+
+        loop {
+            if found.load(Ordering::Relaxed) {
+                return;
+            }
+            if let Some(deadline) = deadline {
+                if Instant::now() >= deadline {
+                    found.store(true, Ordering::Relaxed);
+                    return;
+                }
+            }
+            if let Some(max_attempts) = args.max_attempts {
+                if attempts.load(Ordering::Relaxed) >= max_attempts {
+                    found.store(true, Ordering::Relaxed);
+                    return;
+                }
+            }
+
+            let mut salt = [0u8; 32];
+            salt[24..32].copy_from_slice(&salt_value.to_be_bytes());
+            let address = calculate_address(args.scheme, factory, salt_value, &salt, init_code_hash);
+            let score = score_zero_bytes(&address);
+
+            if score > best_score.load(Ordering::Relaxed) {
+                if score > best_score.fetch_max(score, Ordering::Relaxed) {
+                    *best.lock().unwrap() = Some((salt, address));
+                }
+                if score >= target_score {
+                    found.store(true, Ordering::Relaxed);
+                    return;
+                }
+            }
+
+            salt_value += 1;
+            local_attempts += 1;
+
+            if local_attempts % 10000 == 0 {
+                attempts.fetch_add(10000, Ordering::Relaxed);
+                local_attempts = 0;
+            }
+        }
+    });
+
+    let elapsed = start_time.elapsed();
+    let total_attempts = attempts.load(Ordering::Relaxed);
+
+    match best.lock().unwrap().as_ref() {
+        Some((salt, address)) => {
+            println!("\nâ”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”");
+            println!("âœ… BEST ADDRESS FOUND");
+            println!("â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”");
+            println!("Salt:      0x{}", hex::encode(salt));
+            println!("Address:   0x{}", to_checksum_address(address));
+            println!("Score:     {}", best_score.load(Ordering::Relaxed));
+            println!("Attempts:  {}", format_number(total_attempts));
+            println!("Time:      {:.2}s", elapsed.as_secs_f64());
+            println!("Rate:      {:.0} H/s", total_attempts as f64 / elapsed.as_secs_f64());
+            println!("â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”â”\n");
+        }
+        None => println!("\nâŒ Search was interrupted before finding any candidate\n"),
+    }
+}
+
 fn format_number(n: u64) -> String {
     let s = n.to_string();
     let mut result = String::new();
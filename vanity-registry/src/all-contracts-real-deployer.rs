@@ -2,9 +2,147 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 use std::fs;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// A constructor argument value, tagged with enough type information to know
+/// whether it belongs in the ABI-encoded head (fixed-size) or tail (dynamic).
+#[derive(Clone, Debug)]
+enum AbiValue {
+    Address([u8; 20]),
+    Uint256([u8; 32]),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+impl AbiValue {
+    fn address(s: &str) -> AbiValue {
+        let bytes = hex::decode(s.trim_start_matches("0x")).expect("invalid address hex");
+        AbiValue::Address(bytes.try_into().expect("address must be 20 bytes"))
+    }
+
+    /// Whether the Solidity ABI spec encodes this type in the dynamic tail
+    /// (with an offset pointer in the head) rather than inline in the head.
+    fn is_dynamic(&self) -> bool {
+        matches!(self, AbiValue::String(_) | AbiValue::Bytes(_))
+    }
+
+    /// Encodes a static value as its single 32-byte head word.
+    fn encode_static(&self) -> [u8; 32] {
+        match self {
+            AbiValue::Address(addr) => {
+                let mut word = [0u8; 32];
+                word[12..32].copy_from_slice(addr);
+                word
+            }
+            AbiValue::Uint256(value) => *value,
+            AbiValue::String(_) | AbiValue::Bytes(_) => {
+                unreachable!("dynamic types are encoded via encode_tail, not encode_static")
+            }
+        }
+    }
+
+    /// Encodes a dynamic value's tail entry: a 32-byte length word followed
+    /// by the right-padded data, padded out to a multiple of 32 bytes.
+    fn encode_tail(&self) -> Vec<u8> {
+        let bytes: &[u8] = match self {
+            AbiValue::String(s) => s.as_bytes(),
+            AbiValue::Bytes(b) => b,
+            AbiValue::Address(_) | AbiValue::Uint256(_) => {
+                unreachable!("static types have no tail entry")
+            }
+        };
+
+        let mut out = Vec::with_capacity(32 + bytes.len().div_ceil(32) * 32);
+        let mut len_word = [0u8; 32];
+        len_word[24..32].copy_from_slice(&(bytes.len() as u64).to_be_bytes());
+        out.extend_from_slice(&len_word);
+        out.extend_from_slice(bytes);
+        let padding = bytes.len().div_ceil(32) * 32 - bytes.len();
+        out.extend(std::iter::repeat(0u8).take(padding));
+        out
+    }
+}
+
+/// Whether a Solidity ABI type string is encoded in the dynamic tail.
+fn is_dynamic_type(ty: &str) -> bool {
+    ty == "string" || ty == "bytes" || ty.ends_with("[]")
+}
+
+/// Encodes `args` as Solidity ABI constructor parameters, per the artifact's
+/// own `abi` entry: a static head of 32-byte words (dynamic entries holding
+/// an offset pointer instead of their value), followed by a tail holding the
+/// length-prefixed, right-padded dynamic data.
+fn encode_constructor_args(artifact: &serde_json::Value, args: &[AbiValue]) -> Vec<u8> {
+    let abi = artifact["abi"]
+        .as_array()
+        .expect("artifact is missing an `abi` array");
+    let constructor = abi
+        .iter()
+        .find(|entry| entry["type"] == "constructor")
+        .expect("artifact abi has no constructor entry");
+    let inputs = constructor["inputs"].as_array().cloned().unwrap_or_default();
+
+    assert_eq!(
+        inputs.len(),
+        args.len(),
+        "constructor expects {} args, got {}",
+        inputs.len(),
+        args.len()
+    );
+    for (input, arg) in inputs.iter().zip(args) {
+        let ty = input["type"].as_str().unwrap_or("");
+        assert_eq!(
+            is_dynamic_type(ty),
+            arg.is_dynamic(),
+            "constructor arg for `{}` does not match its ABI type `{}`",
+            input["name"].as_str().unwrap_or("<unnamed>"),
+            ty
+        );
+    }
+
+    let head_len = args.len() * 32;
+    let mut head = Vec::with_capacity(args.len());
+    let mut tail = Vec::new();
+
+    for arg in args {
+        if arg.is_dynamic() {
+            let mut offset_word = [0u8; 32];
+            offset_word[24..32].copy_from_slice(&((head_len + tail.len()) as u64).to_be_bytes());
+            head.push(offset_word);
+            tail.extend(arg.encode_tail());
+        } else {
+            head.push(arg.encode_static());
+        }
+    }
+
+    let mut encoded = Vec::with_capacity(head_len + tail.len());
+    for word in head {
+        encoded.extend_from_slice(&word);
+    }
+    encoded.extend(tail);
+    encoded
+}
+
+/// Reads a Foundry artifact's bytecode and appends the ABI-encoded
+/// constructor `args`, producing the full init code for CREATE2 hashing.
+fn build_init_code(artifact_path: &str, args: &[AbiValue]) -> Vec<u8> {
+    let artifact_content = fs::read_to_string(artifact_path)
+        .unwrap_or_else(|e| panic!("failed to read artifact {}: {}", artifact_path, e));
+    let artifact: serde_json::Value =
+        serde_json::from_str(&artifact_content).expect("failed to parse artifact JSON");
+
+    let bytecode_hex = artifact["bytecode"]["object"]
+        .as_str()
+        .expect("bytecode not found in artifact")
+        .trim_start_matches("0x");
+    let mut init_code = hex::decode(bytecode_hex).expect("invalid bytecode hex");
+
+    init_code.extend(encode_constructor_args(&artifact, args));
+    init_code
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 struct VanityResult {
@@ -12,6 +150,7 @@ struct VanityResult {
     salt: String,
     address: String,
     deployer: String,
+    init_code_hash: String,
     attempts: u64,
     time_seconds: f64,
     pattern: String,
@@ -25,74 +164,403 @@ struct AllResults {
     deployer: String,
 }
 
+/// A complete, ready-to-broadcast deployment payload for one contract: the
+/// factory calldata (`salt ++ init_code`), the predicted address, and enough
+/// network context that the artifact is unambiguous about where it targets.
+#[derive(Serialize)]
+struct DeploymentArtifact {
+    contract_name: String,
+    network: String,
+    chain_id: u64,
+    factory: String,
+    salt: String,
+    init_code_hash: String,
+    predicted_address: String,
+    calldata: String,
+}
+
+/// Builds a [`DeploymentArtifact`] from a found [`VanityResult`], re-deriving
+/// the predicted address from the scheme/salt/init-code-hash and asserting
+/// it still matches the recorded address before anything is written out.
+fn build_deployment_artifact(
+    scheme: &AddressScheme,
+    init_code: &[u8],
+    result: &VanityResult,
+    network: &str,
+    chain_id: u64,
+) -> DeploymentArtifact {
+    let salt_bytes = hex::decode(result.salt.trim_start_matches("0x")).expect("invalid salt hex");
+    let init_code_hash_bytes = hex::decode(result.init_code_hash.trim_start_matches("0x"))
+        .expect("invalid init code hash hex");
+    let salt: [u8; 32] = salt_bytes
+        .clone()
+        .try_into()
+        .expect("salt must be 32 bytes");
+
+    let recomputed = scheme.address(&salt, &init_code_hash_bytes);
+    let recomputed_hex = format!("0x{}", hex::encode(recomputed));
+    assert_eq!(
+        recomputed_hex, result.address,
+        "address re-verification failed for {}: recorded {}, recomputed {}",
+        result.contract_name, result.address, recomputed_hex
+    );
+
+    let mut calldata = salt_bytes;
+    calldata.extend_from_slice(init_code);
+
+    DeploymentArtifact {
+        contract_name: result.contract_name.clone(),
+        network: network.to_string(),
+        chain_id,
+        factory: format!("0x{}", hex::encode(scheme.factory())),
+        salt: result.salt.clone(),
+        init_code_hash: result.init_code_hash.clone(),
+        predicted_address: result.address.clone(),
+        calldata: format!("0x{}", hex::encode(calldata)),
+    }
+}
+
+/// Top-level batch job file: which deployer/factory and salt space to use,
+/// and the list of per-contract jobs to run against them.
+#[derive(Deserialize)]
+struct JobFile {
+    deployer: String,
+    /// Human-readable network name (e.g. `"ethereum-mainnet"`), carried into
+    /// deployment artifacts so they're unambiguous about their target.
+    network: String,
+    chain_id: u64,
+    address_scheme: AddressSchemeSpec,
+    #[serde(default)]
+    salt_space: SaltSpaceSpec,
+    jobs: Vec<ContractJobSpec>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AddressSchemeSpec {
+    Create2 { factory: String },
+    Create3 { factory: String },
+    Create2ViaFactory { factory: String },
+}
+
+impl AddressSchemeSpec {
+    fn resolve(&self) -> AddressScheme {
+        match self {
+            AddressSchemeSpec::Create2 { factory } => AddressScheme::Create2 {
+                factory: decode_address_bytes(factory),
+            },
+            AddressSchemeSpec::Create3 { factory } => AddressScheme::Create3 {
+                factory: decode_address_bytes(factory),
+            },
+            AddressSchemeSpec::Create2ViaFactory { factory } => AddressScheme::Create2ViaFactory {
+                factory: decode_address_bytes(factory),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SaltSpaceSpec {
+    #[default]
+    Full,
+    CallerPrefixed {
+        caller: String,
+    },
+    FixedPrefix {
+        bytes: String,
+        free_len: usize,
+    },
+}
+
+impl SaltSpaceSpec {
+    fn resolve(&self) -> SaltSpace {
+        match self {
+            SaltSpaceSpec::Full => SaltSpace::Full,
+            SaltSpaceSpec::CallerPrefixed { caller } => SaltSpace::CallerPrefixed {
+                caller: decode_address_bytes(caller),
+            },
+            SaltSpaceSpec::FixedPrefix { bytes, free_len } => SaltSpace::FixedPrefix {
+                bytes: hex::decode(bytes.trim_start_matches("0x")).expect("invalid fixed_prefix hex"),
+                free_len: *free_len,
+            },
+        }
+    }
+}
+
+/// One contract to mine a vanity salt for: where to load its artifact from,
+/// its typed constructor args, and the address pattern to search for.
+#[derive(Deserialize)]
+struct ContractJobSpec {
+    contract_name: String,
+    artifact_path: String,
+    #[serde(default)]
+    constructor_args: Vec<ArgSpec>,
+    pattern: PatternSpec,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ArgSpec {
+    Address { value: String },
+    Uint256 { value: String },
+    String { value: String },
+    Bytes { value: String },
+}
+
+impl ArgSpec {
+    fn resolve(&self) -> AbiValue {
+        match self {
+            ArgSpec::Address { value } => AbiValue::address(value),
+            ArgSpec::Uint256 { value } => {
+                let n: u128 = value.parse().expect("invalid uint256 value");
+                let mut word = [0u8; 32];
+                word[16..32].copy_from_slice(&n.to_be_bytes());
+                AbiValue::Uint256(word)
+            }
+            ArgSpec::String { value } => AbiValue::String(value.clone()),
+            ArgSpec::Bytes { value } => {
+                AbiValue::Bytes(hex::decode(value.trim_start_matches("0x")).expect("invalid bytes hex"))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct PatternSpec {
+    #[serde(default)]
+    prefix: String,
+    #[serde(default)]
+    suffix: String,
+    #[serde(default)]
+    contains: Vec<String>,
+    #[serde(default)]
+    leading_zero_bytes: usize,
+    #[serde(default)]
+    checksum: bool,
+    label: Option<String>,
+}
+
+impl PatternSpec {
+    fn resolve(&self) -> Pattern {
+        let label = self.label.clone().unwrap_or_else(|| {
+            format!("0x{}...{}", self.prefix, self.suffix)
+        });
+        Pattern {
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            contains: self.contains.clone(),
+            leading_zero_bytes: self.leading_zero_bytes,
+            checksum: self.checksum,
+            label,
+        }
+    }
+}
+
+fn decode_address_bytes(s: &str) -> Vec<u8> {
+    hex::decode(s.trim_start_matches("0x")).expect("invalid address hex")
+}
+
+/// Parsed command line: the job file to run, plus optional sharding,
+/// checkpointing, and a `--merge` mode for combining per-shard result files.
+struct Cli {
+    job_path: String,
+    shard: ShardConfig,
+    checkpoint_dir: Option<String>,
+    artifacts_dir: Option<String>,
+    merge_paths: Vec<String>,
+}
+
+fn parse_cli() -> Cli {
+    let mut job_path = "../vanity-job.json".to_string();
+    let mut shard = ShardConfig::default();
+    let mut checkpoint_dir = None;
+    let mut artifacts_dir = None;
+    let mut merge_paths = Vec::new();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--shard" => {
+                i += 1;
+                let spec = args.get(i).expect("--shard requires a k/n value, e.g. 0/4");
+                let (k, n) = spec
+                    .split_once('/')
+                    .expect("--shard expects k/n, e.g. 0/4");
+                shard.shard_index = k.parse().expect("invalid shard index");
+                shard.shard_count = n.parse().expect("invalid shard count");
+            }
+            "--checkpoint-dir" => {
+                i += 1;
+                checkpoint_dir = Some(
+                    args.get(i)
+                        .expect("--checkpoint-dir requires a path")
+                        .clone(),
+                );
+            }
+            "--artifacts-dir" => {
+                i += 1;
+                artifacts_dir = Some(
+                    args.get(i)
+                        .expect("--artifacts-dir requires a path")
+                        .clone(),
+                );
+            }
+            "--resume" => shard.resume = true,
+            "--merge" => {
+                i += 1;
+                merge_paths.extend(args[i..].iter().cloned());
+                i = args.len();
+            }
+            other => job_path = other.to_string(),
+        }
+        i += 1;
+    }
+
+    Cli {
+        job_path,
+        shard,
+        checkpoint_dir,
+        artifacts_dir,
+        merge_paths,
+    }
+}
+
+/// Combines per-shard `AllResults` files (produced by cooperating `--shard
+/// k/n` processes) into one, keeping the first result seen per contract.
+fn merge_shard_results(paths: &[String]) {
+    let mut by_contract: Vec<VanityResult> = Vec::new();
+    let mut deployer = String::new();
+    let mut total_time_seconds = 0.0f64;
+
+    for path in paths {
+        let content =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e));
+        let shard_results: AllResults = serde_json::from_str(&content)
+            .unwrap_or_else(|e| panic!("failed to parse {}: {}", path, e));
+
+        if deployer.is_empty() {
+            deployer = shard_results.deployer.clone();
+        }
+        total_time_seconds = total_time_seconds.max(shard_results.total_time_seconds);
+
+        for result in shard_results.results {
+            if !by_contract.iter().any(|r| r.contract_name == result.contract_name) {
+                by_contract.push(result);
+            }
+        }
+    }
+
+    let merged = AllResults {
+        results: by_contract,
+        total_time_seconds,
+        deployer,
+    };
+
+    let json = serde_json::to_string_pretty(&merged).unwrap();
+    fs::write("../vanity-addresses-real-deployer.json", json).expect("Failed to write merged results");
+
+    println!("Merged {} shard file(s) into vanity-addresses-real-deployer.json", paths.len());
+    for result in &merged.results {
+        println!("{:20} → {}", result.contract_name, result.address);
+    }
+}
+
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("verify") {
+        let job_path = std::env::args()
+            .nth(2)
+            .expect("verify requires a job file path");
+        let results_path = std::env::args()
+            .nth(3)
+            .expect("verify requires a results file path");
+        run_verify(&job_path, &results_path);
+        return;
+    }
+
+    let cli = parse_cli();
+
+    if !cli.merge_paths.is_empty() {
+        merge_shard_results(&cli.merge_paths);
+        return;
+    }
+
     println!("\n╔══════════════════════════════════════════════════════════╗");
-    println!("║  🦀 VANITY GENERATOR - ALL CONTRACTS                   ║");
-    println!("║      Real Deployer: 0x7310Dd6EF89b7f829839F140C6840bc929ba2031 ║");
-    println!("║      Pattern: 0x47...ea91e (FULL MATCH)                 ║");
+    println!("║  🦀 VANITY GENERATOR - BATCH RUNNER                     ║");
     println!("╚══════════════════════════════════════════════════════════╝\n");
+    println!("Job file: {}", cli.job_path);
+
+    let job_content = fs::read_to_string(&cli.job_path)
+        .unwrap_or_else(|e| panic!("failed to read job file {}: {}", cli.job_path, e));
+    let job_file: JobFile = serde_json::from_str(&job_content)
+        .unwrap_or_else(|e| panic!("failed to parse job file {}: {}", cli.job_path, e));
 
-    // YOUR ACTUAL DEPLOYER ADDRESS
-    let deployer = "0x7310Dd6EF89b7f829839F140C6840bc929ba2031";
-    let deployer_bytes = hex::decode(&deployer[2..]).expect("Invalid deployer address");
+    let scheme = job_file.address_scheme.resolve();
+    let salt_space = job_file.salt_space.resolve();
 
-    println!("Deployer: {}", deployer);
-    println!("Pattern:  0x47...ea91e (FULL)");
+    println!("Deployer: {}", job_file.deployer);
+    println!("Jobs:     {}", job_file.jobs.len());
     println!();
 
     let mut all_results = Vec::new();
     let total_start = Instant::now();
 
-    // Contract 1: EagleShareOFT (PREMIUM - full pattern)
-    println!("═══════════════════════════════════════════════════════════");
-    println!("CONTRACT 1/4: EagleShareOFT [PREMIUM VANITY]");
-    println!("═══════════════════════════════════════════════════════════\n");
-    
-    let oft_result = generate_vanity_oft(&deployer_bytes, deployer);
-    all_results.push(oft_result);
-    
-    println!("\n");
+    for (idx, job) in job_file.jobs.iter().enumerate() {
+        println!("═══════════════════════════════════════════════════════════");
+        println!(
+            "CONTRACT {}/{}: {}",
+            idx + 1,
+            job_file.jobs.len(),
+            job.contract_name
+        );
+        println!("═══════════════════════════════════════════════════════════\n");
 
-    // Contract 2: EagleOVault (partial pattern 0x47...)
-    println!("═══════════════════════════════════════════════════════════");
-    println!("CONTRACT 2/4: EagleOVault [PARTIAL VANITY]");
-    println!("═══════════════════════════════════════════════════════════\n");
-    
-    let vault_result = generate_vanity_vault(&deployer_bytes, deployer);
-    all_results.push(vault_result);
-    
-    println!("\n");
+        let job_shard = ShardConfig {
+            checkpoint_path: cli
+                .checkpoint_dir
+                .as_ref()
+                .map(|dir| format!("{dir}/{}.checkpoint.json", job.contract_name)),
+            ..cli.shard.clone()
+        };
 
-    // Contract 3: EagleVaultWrapper (partial pattern 0x47...)
-    println!("═══════════════════════════════════════════════════════════");
-    println!("CONTRACT 3/4: EagleVaultWrapper [PARTIAL VANITY]");
-    println!("═══════════════════════════════════════════════════════════\n");
-    
-    let wrapper_result = generate_vanity_wrapper(&deployer_bytes, deployer);
-    all_results.push(wrapper_result);
-    
-    println!("\n");
+        let result = run_job(
+            &scheme,
+            &salt_space,
+            job,
+            &job_file.deployer,
+            &job_shard,
+            cli.artifacts_dir.as_deref(),
+            &job_file.network,
+            job_file.chain_id,
+        );
+        all_results.push(result);
 
-    // Contract 4: CharmStrategyUSD1 (partial pattern 0x47...)
-    println!("═══════════════════════════════════════════════════════════");
-    println!("CONTRACT 4/4: CharmStrategyUSD1 [PARTIAL VANITY]");
-    println!("═══════════════════════════════════════════════════════════\n");
-    
-    let strategy_result = generate_vanity_strategy(&deployer_bytes, deployer);
-    all_results.push(strategy_result);
+        println!("\n");
+    }
 
     let total_elapsed = total_start.elapsed();
 
-    // Save all results
+    // Save all results. A sharded run writes to its own file instead of the
+    // combined output, so cooperating shards don't clobber each other;
+    // `--merge` combines them afterwards.
+    let output_path = if cli.shard.shard_count > 1 {
+        format!(
+            "../vanity-addresses-real-deployer.shard{}.json",
+            cli.shard.shard_index
+        )
+    } else {
+        "../vanity-addresses-real-deployer.json".to_string()
+    };
+
     let all_results_json = AllResults {
         results: all_results.clone(),
         total_time_seconds: total_elapsed.as_secs_f64(),
-        deployer: deployer.to_string(),
+        deployer: job_file.deployer.clone(),
     };
 
     let json = serde_json::to_string_pretty(&all_results_json).unwrap();
-    fs::write("../vanity-addresses-real-deployer.json", json)
-        .expect("Failed to write results file");
+    fs::write(&output_path, json).expect("Failed to write results file");
 
     println!("\n");
     println!("╔══════════════════════════════════════════════════════════╗");
@@ -100,9 +568,19 @@ fn main() {
     println!("╚══════════════════════════════════════════════════════════╝\n");
     println!("Total Time: {:.2} minutes", total_elapsed.as_secs_f64() / 60.0);
     println!();
-    println!("Results saved to: vanity-addresses-real-deployer.json");
+    println!("Results saved to: {}", output_path);
+    if cli.shard.shard_count > 1 {
+        println!(
+            "Once all {} shards finish, combine with: --merge {}",
+            cli.shard.shard_count,
+            (0..cli.shard.shard_count)
+                .map(|k| format!("../vanity-addresses-real-deployer.shard{k}.json"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+    }
     println!();
-    
+
     // Print summary
     println!("SUMMARY:");
     println!("═══════════════════════════════════════════════════════════");
@@ -112,182 +590,372 @@ fn main() {
     println!("═══════════════════════════════════════════════════════════");
 }
 
-fn generate_vanity_oft(deployer_bytes: &[u8], deployer: &str) -> VanityResult {
-    let artifact_path = "../out/EagleShareOFT.sol/EagleShareOFT.json";
-    let artifact_content = fs::read_to_string(artifact_path)
-        .expect("Failed to read EagleShareOFT artifact");
-    
-    let artifact: serde_json::Value = serde_json::from_str(&artifact_content)
-        .expect("Failed to parse artifact JSON");
-    
-    let bytecode_hex = artifact["bytecode"]["object"]
-        .as_str()
-        .expect("Bytecode not found")
-        .trim_start_matches("0x");
-    
-    let name = "Eagle";
-    let symbol = "EAGLE";
-    let registry = "0x47c2e78bCCCdF3E4Ad835c1c2df3Fb760b0EA91E";
-    let delegate = deployer;
-    
-    let constructor_args = format!(
-        "{:0>64}{:0>64}{:0>64}{:0>64}{}{}",
-        "80",
-        "c0",
-        &registry[2..],
-        &delegate[2..],
-        encode_string(name),
-        encode_string(symbol)
-    );
-    
-    let init_code = format!("{}{}", bytecode_hex, constructor_args);
-    let init_code_bytes = hex::decode(&init_code).expect("Invalid init code");
-    
+fn run_job(
+    scheme: &AddressScheme,
+    salt_space: &SaltSpace,
+    job: &ContractJobSpec,
+    deployer: &str,
+    shard: &ShardConfig,
+    artifacts_dir: Option<&str>,
+    network: &str,
+    chain_id: u64,
+) -> VanityResult {
+    let args: Vec<AbiValue> = job.constructor_args.iter().map(ArgSpec::resolve).collect();
+    let init_code = build_init_code(&job.artifact_path, &args);
+
     let mut hasher = Keccak256::new();
-    hasher.update(&init_code_bytes);
+    hasher.update(&init_code);
     let init_code_hash = hasher.finalize();
-    
+
+    let pattern = job.pattern.resolve();
+
     println!("Init Code Hash: 0x{}", hex::encode(&init_code_hash));
-    println!("Searching for FULL pattern: 0x47...ea91e");
     println!();
-    
-    find_vanity_full_pattern(deployer_bytes, &init_code_hash, "EagleShareOFT", deployer)
-}
 
-fn generate_vanity_vault(deployer_bytes: &[u8], deployer: &str) -> VanityResult {
-    let artifact_path = "../out/EagleOVault.sol/EagleOVault.json";
-    let artifact_content = fs::read_to_string(artifact_path)
-        .expect("Failed to read EagleOVault artifact");
-    
-    let artifact: serde_json::Value = serde_json::from_str(&artifact_content)
-        .expect("Failed to parse artifact JSON");
-    
-    let bytecode_hex = artifact["bytecode"]["object"]
-        .as_str()
-        .expect("Bytecode not found")
-        .trim_start_matches("0x");
-    
-    let wlfi = "0xdA5e1988097297dCdc1f90D4dFE7909e847CBeF6";
-    let usd1 = "0x8d0D000Ee44948FC98c9B98A4FA4921476f08B0d";
-    let price_feed = "0xF0d9bb015Cd7BfAb877B7156146dc09Bf461370d";
-    let pool = "0x4637Ea6eCf7E16C99E67E941ab4d7d52eAc7c73d";
-    let router = "0xE592427A0AEce92De3Edee1F18E0157C05861564";
-    
-    let constructor_args = format!(
-        "{:0>64}{:0>64}{:0>64}{:0>64}{:0>64}{:0>64}",
-        &wlfi[2..],
-        &usd1[2..],
-        &price_feed[2..],
-        &pool[2..],
-        &router[2..],
-        &deployer[2..]
+    let result = find_vanity(
+        scheme,
+        salt_space,
+        &init_code_hash,
+        &job.contract_name,
+        deployer,
+        &pattern,
+        shard,
     );
-    
-    let init_code = format!("{}{}", bytecode_hex, constructor_args);
-    let init_code_bytes = hex::decode(&init_code).expect("Invalid init code");
-    
-    let mut hasher = Keccak256::new();
-    hasher.update(&init_code_bytes);
-    let init_code_hash = hasher.finalize();
-    
-    println!("Init Code Hash: 0x{}", hex::encode(&init_code_hash));
-    println!("Searching for PARTIAL pattern: 0x47...");
-    println!();
-    
-    find_vanity_partial_pattern(deployer_bytes, &init_code_hash, "EagleOVault", deployer)
+
+    if let Some(dir) = artifacts_dir {
+        let artifact = build_deployment_artifact(scheme, &init_code, &result, network, chain_id);
+        fs::create_dir_all(dir).expect("failed to create artifacts dir");
+        let path = format!("{dir}/{}.json", result.contract_name);
+        let json = serde_json::to_string_pretty(&artifact).unwrap();
+        fs::write(&path, json).expect("Failed to write deployment artifact");
+        println!("Deployment artifact saved to: {path}");
+    }
+
+    result
 }
 
-fn generate_vanity_wrapper(deployer_bytes: &[u8], deployer: &str) -> VanityResult {
-    let artifact_path = "../out/EagleVaultWrapper.sol/EagleVaultWrapper.json";
-    let artifact_content = fs::read_to_string(artifact_path)
-        .expect("Failed to read EagleVaultWrapper artifact");
-    
-    let artifact: serde_json::Value = serde_json::from_str(&artifact_content)
-        .expect("Failed to parse artifact JSON");
-    
-    let bytecode_hex = artifact["bytecode"]["object"]
-        .as_str()
-        .expect("Bytecode not found")
-        .trim_start_matches("0x");
-    
-    // Use placeholder addresses (will be updated in deployment script)
-    let vault = "0x0000000000000000000000000000000000000001";
-    let oft = "0x0000000000000000000000000000000000000002";
-    
-    let constructor_args = format!(
-        "{:0>64}{:0>64}{:0>64}{:0>64}",
-        &vault[2..],
-        &oft[2..],
-        &deployer[2..],
-        &deployer[2..]
-    );
-    
-    let init_code = format!("{}{}", bytecode_hex, constructor_args);
-    let init_code_bytes = hex::decode(&init_code).expect("Invalid init code");
-    
-    let mut hasher = Keccak256::new();
-    hasher.update(&init_code_bytes);
-    let init_code_hash = hasher.finalize();
-    
-    println!("Init Code Hash: 0x{}", hex::encode(&init_code_hash));
-    println!("Searching for PARTIAL pattern: 0x47...");
-    println!();
-    
-    find_vanity_partial_pattern(deployer_bytes, &init_code_hash, "EagleVaultWrapper", deployer)
+/// Re-derives each result's address from its job-file artifact/args and the
+/// recorded salt, catching stale artifacts or constructor-arg drift before
+/// anyone broadcasts a deployment built from a result file.
+fn run_verify(job_path: &str, results_path: &str) {
+    let job_content = fs::read_to_string(job_path)
+        .unwrap_or_else(|e| panic!("failed to read job file {}: {}", job_path, e));
+    let job_file: JobFile = serde_json::from_str(&job_content)
+        .unwrap_or_else(|e| panic!("failed to parse job file {}: {}", job_path, e));
+    let scheme = job_file.address_scheme.resolve();
+
+    let results_content = fs::read_to_string(results_path)
+        .unwrap_or_else(|e| panic!("failed to read results file {}: {}", results_path, e));
+    let all_results: AllResults = serde_json::from_str(&results_content)
+        .unwrap_or_else(|e| panic!("failed to parse results file {}: {}", results_path, e));
+
+    let mut failures = 0usize;
+
+    for result in &all_results.results {
+        let Some(job) = job_file
+            .jobs
+            .iter()
+            .find(|j| j.contract_name == result.contract_name)
+        else {
+            println!("SKIP {} (not present in job file)", result.contract_name);
+            continue;
+        };
+
+        let args: Vec<AbiValue> = job.constructor_args.iter().map(ArgSpec::resolve).collect();
+        let init_code = build_init_code(&job.artifact_path, &args);
+        let mut hasher = Keccak256::new();
+        hasher.update(&init_code);
+        let init_code_hash = hasher.finalize();
+        let expected_hash = format!("0x{}", hex::encode(init_code_hash));
+
+        if expected_hash != result.init_code_hash {
+            println!(
+                "FAIL {}: init code hash drifted (recorded {}, recomputed {})",
+                result.contract_name, result.init_code_hash, expected_hash
+            );
+            failures += 1;
+            continue;
+        }
+
+        let salt_bytes = hex::decode(result.salt.trim_start_matches("0x")).expect("invalid salt hex");
+        let salt: [u8; 32] = salt_bytes.try_into().expect("salt must be 32 bytes");
+        let address = scheme.address(&salt, &init_code_hash);
+        let address_hex = format!("0x{}", hex::encode(address));
+        let pattern = job.pattern.resolve();
+
+        if address_hex != result.address {
+            println!(
+                "FAIL {}: address mismatch (recorded {}, recomputed {})",
+                result.contract_name, result.address, address_hex
+            );
+            failures += 1;
+        } else if !pattern.matches(&address) {
+            println!(
+                "FAIL {}: recomputed address no longer matches pattern {}",
+                result.contract_name, pattern.label
+            );
+            failures += 1;
+        } else if result.deployer != job_file.deployer {
+            println!(
+                "FAIL {}: recorded deployer {} does not match job file deployer {}",
+                result.contract_name, result.deployer, job_file.deployer
+            );
+            failures += 1;
+        } else {
+            println!("OK   {} -> {}", result.contract_name, address_hex);
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("\n{failures} contract(s) failed verification");
+        std::process::exit(1);
+    }
+    println!("\nAll {} contract(s) verified", all_results.results.len());
 }
 
-fn generate_vanity_strategy(deployer_bytes: &[u8], deployer: &str) -> VanityResult {
-    let artifact_path = "../out/CharmStrategyUSD1.sol/CharmStrategyUSD1.json";
-    let artifact_content = fs::read_to_string(artifact_path)
-        .expect("Failed to read CharmStrategyUSD1 artifact");
-    
-    let artifact: serde_json::Value = serde_json::from_str(&artifact_content)
-        .expect("Failed to parse artifact JSON");
-    
-    let bytecode_hex = artifact["bytecode"]["object"]
-        .as_str()
-        .expect("Bytecode not found")
-        .trim_start_matches("0x");
-    
-    // Use placeholder for vault (will be updated in deployment script)
-    let vault = "0x0000000000000000000000000000000000000001";
-    let charm_vault = "0x22828Dbf15f5FBa2394Ba7Cf8fA9A96BdB444B71";
-    let wlfi = "0xdA5e1988097297dCdc1f90D4dFE7909e847CBeF6";
-    let usd1 = "0x8d0D000Ee44948FC98c9B98A4FA4921476f08B0d";
-    let router = "0xE592427A0AEce92De3Edee1F18E0157C05861564";
-    
-    let constructor_args = format!(
-        "{:0>64}{:0>64}{:0>64}{:0>64}{:0>64}{:0>64}",
-        &vault[2..],
-        &charm_vault[2..],
-        &wlfi[2..],
-        &usd1[2..],
-        &router[2..],
-        &deployer[2..]
-    );
-    
-    let init_code = format!("{}{}", bytecode_hex, constructor_args);
-    let init_code_bytes = hex::decode(&init_code).expect("Invalid init code");
-    
+/// A declarative vanity-address match target, replacing hardcoded per-byte
+/// comparisons with prefix/suffix/contains nibble constraints, a required
+/// leading-zero-byte count, and optional EIP-55 checksum-case matching.
+#[derive(Clone, Debug)]
+struct Pattern {
+    /// Hex nibbles the address must start with.
+    prefix: String,
+    /// Hex nibbles the address must end with.
+    suffix: String,
+    /// Hex nibble substrings that must appear somewhere in the address.
+    contains: Vec<String>,
+    /// Number of leading zero bytes the address must have.
+    leading_zero_bytes: usize,
+    /// If true, `prefix`/`suffix`/`contains` are matched letter-for-letter
+    /// against the EIP-55 checksum casing rather than case-insensitively.
+    checksum: bool,
+    /// Human-readable label stored alongside results (e.g. `"0x47...ea91e"`).
+    label: String,
+}
+
+impl Pattern {
+    fn matches(&self, address: &[u8; 20]) -> bool {
+        if !address
+            .iter()
+            .take(self.leading_zero_bytes)
+            .all(|&b| b == 0)
+        {
+            return false;
+        }
+
+        let candidate = if self.checksum {
+            to_checksum_address(address)
+        } else {
+            hex::encode(address)
+        };
+        let norm = |s: &str| -> String {
+            if self.checksum {
+                s.to_string()
+            } else {
+                s.to_lowercase()
+            }
+        };
+
+        if !self.prefix.is_empty() && !candidate.starts_with(&norm(&self.prefix)) {
+            return false;
+        }
+        if !self.suffix.is_empty() && !candidate.ends_with(&norm(&self.suffix)) {
+            return false;
+        }
+        self.contains
+            .iter()
+            .all(|needle| candidate.contains(&norm(needle)))
+    }
+
+    /// Rough attempts-to-find estimate, in bits: 4 bits per fixed hex
+    /// nibble, 8 bits per required leading zero byte, plus one extra bit
+    /// per letter whose case is additionally pinned by checksum matching.
+    fn difficulty_bits(&self) -> f64 {
+        let fixed_nibbles: usize = self.prefix.len()
+            + self.suffix.len()
+            + self.contains.iter().map(|s| s.len()).sum::<usize>();
+        let cased_letters = if self.checksum {
+            self.prefix
+                .chars()
+                .chain(self.suffix.chars())
+                .chain(self.contains.iter().flat_map(|s| s.chars()))
+                .filter(|c| c.is_ascii_alphabetic())
+                .count()
+        } else {
+            0
+        };
+
+        fixed_nibbles as f64 * 4.0
+            + self.leading_zero_bytes as f64 * 8.0
+            + cased_letters as f64
+    }
+}
+
+/// Encodes `address` as an EIP-55 mixed-case checksummed hex string (no
+/// `0x` prefix): lowercase-hex the address, keccak256 the resulting ASCII
+/// string, then uppercase each letter nibble whose corresponding hash
+/// nibble is >= 8.
+fn to_checksum_address(address: &[u8; 20]) -> String {
+    let lower_hex = hex::encode(address);
+
     let mut hasher = Keccak256::new();
-    hasher.update(&init_code_bytes);
-    let init_code_hash = hasher.finalize();
-    
-    println!("Init Code Hash: 0x{}", hex::encode(&init_code_hash));
-    println!("Searching for PARTIAL pattern: 0x47...");
-    println!();
-    
-    find_vanity_partial_pattern(deployer_bytes, &init_code_hash, "CharmStrategyUSD1", deployer)
+    hasher.update(lower_hex.as_bytes());
+    let hash = hasher.finalize();
+
+    lower_hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if !c.is_ascii_alphabetic() {
+                return c;
+            }
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Which bytes of the 32-byte CREATE2/CREATE3 salt the search is free to
+/// vary, and which are pinned to a required value.
+#[derive(Clone, Debug)]
+enum SaltSpace {
+    /// The full salt is free; the search counter drives its low 8 bytes.
+    Full,
+    /// Front-running-resistant factories (keyless/Safe-style singleton
+    /// deployers) require `salt[0..20] == caller`, leaving only the low 12
+    /// bytes free; the search counter drives the low 8 of those.
+    CallerPrefixed { caller: Vec<u8> },
+    /// A caller-chosen prefix of arbitrary length, with `free_len` bytes
+    /// free after it; the search counter drives the low bytes of that
+    /// free region (up to 8).
+    FixedPrefix { bytes: Vec<u8>, free_len: usize },
+}
+
+impl SaltSpace {
+    /// Builds the salt for search counter `i`, placing it as a big-endian
+    /// counter right-aligned within this space's free byte region, with any
+    /// fixed prefix bytes copied in ahead of it.
+    fn build_salt(&self, i: u64) -> [u8; 32] {
+        let mut salt = [0u8; 32];
+        match self {
+            SaltSpace::Full => {
+                salt[24..].copy_from_slice(&i.to_be_bytes());
+            }
+            SaltSpace::CallerPrefixed { caller } => {
+                salt[0..20].copy_from_slice(caller);
+                salt[24..32].copy_from_slice(&i.to_be_bytes());
+            }
+            SaltSpace::FixedPrefix { bytes, free_len } => {
+                salt[..bytes.len()].copy_from_slice(bytes);
+                let region_end = bytes.len() + free_len;
+                let counter = i.to_be_bytes();
+                let counter_len = counter.len().min(*free_len);
+                salt[region_end - counter_len..region_end]
+                    .copy_from_slice(&counter[8 - counter_len..]);
+            }
+        }
+        salt
+    }
+}
+
+/// Periodic checkpoint of search progress, so a killed or `--shard`-split
+/// run can resume without redoing already-covered salt counters.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+struct Checkpoint {
+    /// One past the highest salt-counter index any worker had reached when
+    /// this was saved. Lower indices are not all guaranteed to have been
+    /// tried, so resuming from here can skip some of them.
+    next_index: u64,
+    attempts: u64,
+}
+
+/// Reads a checkpoint file written by [`save_checkpoint`], if one exists.
+fn load_checkpoint(path: &str) -> Option<Checkpoint> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Atomically writes `checkpoint` to `path` via a temp file + rename, so a
+/// crash mid-write never leaves a corrupt checkpoint behind.
+fn save_checkpoint(path: &str, checkpoint: Checkpoint) {
+    let tmp_path = format!("{path}.tmp");
+    let json = serde_json::to_string(&checkpoint).expect("serialize checkpoint");
+    if fs::write(&tmp_path, json).is_ok() {
+        let _ = fs::rename(&tmp_path, path);
+    }
+}
+
+/// Removes the checkpoint file once a search completes successfully.
+fn clear_checkpoint(path: &str) {
+    if Path::new(path).exists() {
+        let _ = fs::remove_file(path);
+    }
 }
 
-fn find_vanity_full_pattern(
-    deployer: &[u8],
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Shards the 64-bit salt-counter space across `shard_count` cooperating
+/// processes, each trying `nonce = k*shard_count + shard_index` for a
+/// non-overlapping, deterministic (and therefore resumable) subset.
+#[derive(Clone, Debug)]
+struct ShardConfig {
+    shard_index: u64,
+    shard_count: u64,
+    checkpoint_path: Option<String>,
+    resume: bool,
+}
+
+impl Default for ShardConfig {
+    fn default() -> Self {
+        ShardConfig {
+            shard_index: 0,
+            shard_count: 1,
+            checkpoint_path: None,
+            resume: false,
+        }
+    }
+}
+
+fn find_vanity(
+    scheme: &AddressScheme,
+    salt_space: &SaltSpace,
     init_code_hash: &[u8],
     contract_name: &str,
     deployer_str: &str,
+    pattern: &Pattern,
+    shard: &ShardConfig,
 ) -> VanityResult {
+    println!(
+        "Pattern: {} (~2^{:.1} expected attempts)",
+        pattern.label,
+        pattern.difficulty_bits()
+    );
+    if shard.shard_count > 1 {
+        println!("Shard:   {}/{}", shard.shard_index, shard.shard_count);
+    }
+
+    let base_index = shard
+        .checkpoint_path
+        .as_deref()
+        .filter(|_| shard.resume)
+        .and_then(load_checkpoint)
+        .map(|c| c.next_index)
+        .unwrap_or(0);
+    if base_index > 0 {
+        println!("Resuming from checkpoint at index {base_index}");
+    }
+
     let found = Arc::new(AtomicBool::new(false));
     let attempts = Arc::new(AtomicU64::new(0));
+    let max_k_seen = Arc::new(AtomicU64::new(0));
     let start = Instant::now();
 
     let attempts_clone = attempts.clone();
@@ -311,96 +979,62 @@ fn find_vanity_full_pattern(
         }
     });
 
-    let result = (0u64..u64::MAX)
-        .into_par_iter()
-        .find_map_any(|i| {
+    // Periodically checkpoint the highest counter offset any worker has
+    // reached, so a crash or `--shard`-split run can resume without redoing
+    // work already covered.
+    if let Some(path) = shard.checkpoint_path.clone() {
+        let found = Arc::clone(&found);
+        let attempts = Arc::clone(&attempts);
+        let max_k_seen = Arc::clone(&max_k_seen);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(CHECKPOINT_INTERVAL);
             if found.load(Ordering::Relaxed) {
-                return None;
-            }
-
-            attempts.fetch_add(1, Ordering::Relaxed);
-
-            let salt = i.to_be_bytes();
-            let mut salt_32 = [0u8; 32];
-            salt_32[24..].copy_from_slice(&salt);
-
-            let address = calculate_create2_address(deployer, &salt_32, init_code_hash);
-
-            // FULL PATTERN: 0x47...ea91e
-            if address[0] == 0x47
-                && address[17] == 0x0e
-                && address[18] == 0xa9
-                && address[19] == 0x1e
-            {
-                found.store(true, Ordering::Relaxed);
-                Some((salt_32, address, i))
-            } else {
-                None
+                break;
             }
+            save_checkpoint(
+                &path,
+                Checkpoint {
+                    next_index: max_k_seen.load(Ordering::Relaxed) + 1,
+                    attempts: attempts.load(Ordering::Relaxed),
+                },
+            );
         });
-
-    let elapsed = start.elapsed();
-    let total_attempts = attempts.load(Ordering::Relaxed);
-
-    if let Some((salt, address, _)) = result {
-        println!("✅ FOUND!");
-        println!("Salt:    0x{}", hex::encode(salt));
-        println!("Address: 0x{}", hex::encode(address));
-        println!("Time:    {:.2} seconds", elapsed.as_secs_f64());
-
-        VanityResult {
-            contract_name: contract_name.to_string(),
-            salt: format!("0x{}", hex::encode(salt)),
-            address: format!("0x{}", hex::encode(address)),
-            deployer: deployer_str.to_string(),
-            attempts: total_attempts,
-            time_seconds: elapsed.as_secs_f64(),
-            pattern: "0x47...ea91e".to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-        }
-    } else {
-        panic!("Vanity address not found");
     }
-}
 
-fn find_vanity_partial_pattern(
-    deployer: &[u8],
-    init_code_hash: &[u8],
-    contract_name: &str,
-    deployer_str: &str,
-) -> VanityResult {
-    let found = Arc::new(AtomicBool::new(false));
-    let attempts = Arc::new(AtomicU64::new(0));
-    let start = Instant::now();
+    let result = (0u64..).into_par_iter().find_map_any(|k| {
+        if found.load(Ordering::Relaxed) {
+            return None;
+        }
 
-    let result = (0u64..u64::MAX)
-        .into_par_iter()
-        .find_map_any(|i| {
-            if found.load(Ordering::Relaxed) {
-                return None;
-            }
+        let k = base_index + k;
+        let nonce = k
+            .checked_mul(shard.shard_count)
+            .and_then(|v| v.checked_add(shard.shard_index))
+            .expect("salt counter overflowed u64");
 
-            attempts.fetch_add(1, Ordering::Relaxed);
+        attempts.fetch_add(1, Ordering::Relaxed);
+        max_k_seen.fetch_max(k, Ordering::Relaxed);
 
-            let salt = i.to_be_bytes();
-            let mut salt_32 = [0u8; 32];
-            salt_32[24..].copy_from_slice(&salt);
+        let salt_32 = salt_space.build_salt(nonce);
 
-            let address = calculate_create2_address(deployer, &salt_32, init_code_hash);
+        let address = scheme.address(&salt_32, init_code_hash);
 
-            // PARTIAL PATTERN: just 0x47...
-            if address[0] == 0x47 {
-                found.store(true, Ordering::Relaxed);
-                Some((salt_32, address, i))
-            } else {
-                None
-            }
-        });
+        if pattern.matches(&address) {
+            found.store(true, Ordering::Relaxed);
+            Some((salt_32, address))
+        } else {
+            None
+        }
+    });
 
     let elapsed = start.elapsed();
     let total_attempts = attempts.load(Ordering::Relaxed);
 
-    if let Some((salt, address, _)) = result {
+    if let Some(path) = &shard.checkpoint_path {
+        clear_checkpoint(path);
+    }
+
+    if let Some((salt, address)) = result {
         println!("✅ FOUND!");
         println!("Salt:    0x{}", hex::encode(salt));
         println!("Address: 0x{}", hex::encode(address));
@@ -411,9 +1045,10 @@ fn find_vanity_partial_pattern(
             salt: format!("0x{}", hex::encode(salt)),
             address: format!("0x{}", hex::encode(address)),
             deployer: deployer_str.to_string(),
+            init_code_hash: format!("0x{}", hex::encode(init_code_hash)),
             attempts: total_attempts,
             time_seconds: elapsed.as_secs_f64(),
-            pattern: "0x47...".to_string(),
+            pattern: pattern.label.clone(),
             timestamp: chrono::Utc::now().to_rfc3339(),
         }
     } else {
@@ -434,16 +1069,69 @@ fn calculate_create2_address(deployer: &[u8], salt: &[u8; 32], init_code_hash: &
     address
 }
 
-fn encode_string(s: &str) -> String {
-    let bytes = s.as_bytes();
-    let len = bytes.len();
-    let padded_len = ((len + 31) / 32) * 32;
+/// `keccak256` of the minimal CREATE3 proxy's init code, used by every
+/// CREATE3 factory to derive the proxy's CREATE2 address from a salt.
+const CREATE3_PROXY_INIT_CODE_HASH: [u8; 32] = [
+    0x21, 0xc3, 0x5d, 0xbe, 0x1b, 0x34, 0x4a, 0x24, 0x88, 0xcf, 0x33, 0x21, 0xd6, 0xce, 0x54, 0x2f,
+    0x8e, 0x9f, 0x30, 0x55, 0x44, 0xff, 0x09, 0xe4, 0x99, 0x3a, 0x62, 0x31, 0x9a, 0x49, 0x7c, 0x1f,
+];
+
+/// Derives a CREATE3-deployed contract's address: the factory CREATE2-deploys
+/// a minimal proxy at a salt-derived address (independent of the real init
+/// code), then that proxy CREATE-deploys the real contract at its own
+/// nonce-1 address, `keccak256(rlp([proxy, 1]))[12..]`.
+fn calculate_create3_address(factory: &[u8], salt: &[u8; 32]) -> [u8; 20] {
+    let proxy = calculate_create2_address(factory, salt, &CREATE3_PROXY_INIT_CODE_HASH);
+
+    // rlp([proxy(20 bytes), nonce=1]): nonce 1 encodes as the single byte
+    // 0x01, so the list/string length prefixes are constant.
+    let mut preimage = [0u8; 23];
+    preimage[0] = 0xd6;
+    preimage[1] = 0x94;
+    preimage[2..22].copy_from_slice(&proxy);
+    preimage[22] = 0x01;
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&preimage);
+    let hash = hasher.finalize();
 
-    format!(
-        "{:0>64}{}",
-        format!("{:x}", len),
-        hex::encode(bytes) + &"0".repeat((padded_len - len) * 2)
-    )
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Which CREATE-family scheme derives the deployed contract's address from
+/// a salt, and via which on-chain factory.
+#[derive(Clone, Debug)]
+enum AddressScheme {
+    /// Classic CREATE2: `keccak256(0xff ++ factory ++ salt ++ init_code_hash)[12..]`.
+    Create2 { factory: Vec<u8> },
+    /// Two-step CREATE3 (e.g. a shared CREATE3 factory singleton): the final
+    /// address depends only on `factory` and `salt`, independent of init code.
+    Create3 { factory: Vec<u8> },
+    /// A CREATE2 deployment routed through an intermediary factory contract
+    /// (e.g. a permissioned deployer) that uses CREATE2 internally with the
+    /// caller-supplied salt and init code unchanged.
+    Create2ViaFactory { factory: Vec<u8> },
+}
+
+impl AddressScheme {
+    fn address(&self, salt: &[u8; 32], init_code_hash: &[u8]) -> [u8; 20] {
+        match self {
+            AddressScheme::Create2 { factory } | AddressScheme::Create2ViaFactory { factory } => {
+                calculate_create2_address(factory, salt, init_code_hash)
+            }
+            AddressScheme::Create3 { factory } => calculate_create3_address(factory, salt),
+        }
+    }
+
+    fn factory(&self) -> &[u8] {
+        match self {
+            AddressScheme::Create2 { factory }
+            | AddressScheme::Create2ViaFactory { factory }
+            | AddressScheme::Create3 { factory } => factory,
+        }
+    }
 }
 
 trait ToFormattedString {